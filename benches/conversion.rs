@@ -0,0 +1,132 @@
+#[macro_use]
+extern crate bencher;
+extern crate phf;
+extern crate wanakana;
+
+use bencher::Bencher;
+use wanakana::ConvertChars;
+
+mod phf_lookup;
+mod range_scan;
+
+const ROMAJI: &str = "kyouhaiitenkidesune. watashihatoukyoudeumaretemacchawonomimasu. \
+                      konnichiha, shin'youkinkonotsugi no kaigihanannjidesuka? ";
+const HIRAGANA: &str = "きょうはいいてんきですね。わたしはとうきょうでうまれてまっちゃをのみます。\
+                        こんにちは、しんようきんこのつぎのかいぎはなんじですか？";
+const KATAKANA: &str = "キョウハイイテンキデスネ。ワタシハトーキョーデウマレテマッチャヲノミマス。\
+                        コンニチハ、シンヨウキンコノツギノカイギハナンジデスカ？";
+
+/// Repeat `text` to build a large corpus
+fn corpus(text: &str) -> String {
+    text.repeat(1000)
+}
+
+fn to_kana(bench: &mut Bencher) {
+    let input = corpus(ROMAJI);
+    bench.bytes = input.len() as u64;
+    bench.iter(|| wanakana::to_kana(&input));
+}
+
+fn to_kana_phf_lookup(bench: &mut Bencher) {
+    let input = corpus(ROMAJI);
+    bench.bytes = input.len() as u64;
+    bench.iter(|| phf_lookup::to_kana(&input));
+}
+
+fn stream_to_kana(bench: &mut Bencher) {
+    let input = corpus(ROMAJI);
+    bench.bytes = input.len() as u64;
+    bench.iter(|| input.chars().to_kana().collect::<String>());
+}
+
+fn to_romaji(bench: &mut Bencher) {
+    let input = corpus(HIRAGANA);
+    bench.bytes = input.len() as u64;
+    bench.iter(|| wanakana::to_romaji(&input));
+}
+
+fn to_romaji_phf_lookup(bench: &mut Bencher) {
+    let input = corpus(HIRAGANA);
+    bench.bytes = input.len() as u64;
+    bench.iter(|| phf_lookup::to_romaji(&input));
+}
+
+fn katakana_to_hiragana(bench: &mut Bencher) {
+    let input = corpus(KATAKANA);
+    bench.bytes = input.len() as u64;
+    bench.iter(|| wanakana::katakana_to_hiragana(&input));
+}
+
+fn katakana_to_hiragana_phf_lookup(bench: &mut Bencher) {
+    let input = corpus(KATAKANA);
+    bench.bytes = input.len() as u64;
+    bench.iter(|| phf_lookup::katakana_to_hiragana(&input));
+}
+
+fn is_japanese(bench: &mut Bencher) {
+    let input = corpus(HIRAGANA) + &corpus(KATAKANA);
+    bench.bytes = input.len() as u64;
+    bench.iter(|| wanakana::is_japanese(&input));
+}
+
+fn is_japanese_range_scan(bench: &mut Bencher) {
+    let input = corpus(HIRAGANA) + &corpus(KATAKANA);
+    bench.bytes = input.len() as u64;
+    bench.iter(|| range_scan::is_japanese(&input));
+}
+
+fn is_kana(bench: &mut Bencher) {
+    let input = corpus(KATAKANA).replace(['。', '、', '？'], "");
+    bench.bytes = input.len() as u64;
+    bench.iter(|| wanakana::is_kana(&input));
+}
+
+fn is_kana_range_scan(bench: &mut Bencher) {
+    let input = corpus(KATAKANA).replace(['。', '、', '？'], "");
+    bench.bytes = input.len() as u64;
+    bench.iter(|| range_scan::is_kana(&input));
+}
+
+fn is_romaji(bench: &mut Bencher) {
+    let input = corpus(ROMAJI);
+    bench.bytes = input.len() as u64;
+    bench.iter(|| wanakana::is_romaji(&input));
+}
+
+fn is_romaji_range_scan(bench: &mut Bencher) {
+    let input = corpus(ROMAJI);
+    bench.bytes = input.len() as u64;
+    bench.iter(|| range_scan::is_romaji(&input));
+}
+
+fn is_mixed(bench: &mut Bencher) {
+    let input = corpus(ROMAJI) + &corpus(HIRAGANA);
+    bench.bytes = input.len() as u64;
+    bench.iter(|| wanakana::is_mixed(&input, false));
+}
+
+fn is_mixed_range_scan(bench: &mut Bencher) {
+    let input = corpus(ROMAJI) + &corpus(HIRAGANA);
+    bench.bytes = input.len() as u64;
+    bench.iter(|| range_scan::is_mixed(&input, false));
+}
+
+benchmark_group!(
+    benches,
+    to_kana,
+    to_kana_phf_lookup,
+    stream_to_kana,
+    to_romaji,
+    to_romaji_phf_lookup,
+    katakana_to_hiragana,
+    katakana_to_hiragana_phf_lookup,
+    is_japanese,
+    is_japanese_range_scan,
+    is_kana,
+    is_kana_range_scan,
+    is_romaji,
+    is_romaji_range_scan,
+    is_mixed,
+    is_mixed_range_scan
+);
+benchmark_main!(benches);
@@ -0,0 +1,188 @@
+// The romaji tables as they were before the tries, with a phf map lookup (of a new `String`) for
+// every candidate chunk, kept as a baseline for the benchmarks of the trie-backed conversions
+
+use std::cmp;
+use wanakana::CharExt;
+
+include!(concat!(env!("OUT_DIR"), "/phf_lookup.rs"));
+
+const PROLONGED_SOUND_MARK: char = 'ー';
+const KANA_SLASH_DOT: char = '・';
+const KATAKANA_TO_HIRAGANA_OFFSET: u32 = 0x60;
+
+const FOUR_CHAR_EDGECASES: &[&str] = &["lts", "chy", "shy"];
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'i' | 'u' | 'e' | 'o')
+}
+
+fn is_consonant(c: char, include_y: bool) -> bool {
+    match c {
+        'y' => include_y,
+        'a' | 'i' | 'u' | 'e' | 'o' => false,
+        c => c.is_ascii_lowercase(),
+    }
+}
+
+/// `to_kana` with the default options
+pub fn to_kana(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut kana = String::with_capacity(input.len() * 3);
+    let mut cursor = 0;
+
+    while cursor < chars.len() {
+        let (chunk_size, kana_chunk) = next_kana(&chars[cursor..]);
+
+        match kana_chunk {
+            Some(kana_chunk) if chars[cursor].is_ascii_uppercase() => {
+                kana.push_str(&wanakana::hiragana_to_katakana(kana_chunk))
+            }
+            Some(kana_chunk) => kana.push_str(kana_chunk),
+            None => kana.push(chars[cursor]),
+        }
+
+        cursor += chunk_size;
+    }
+
+    kana
+}
+
+fn next_kana(chars: &[char]) -> (usize, Option<&'static str>) {
+    let lower: Vec<char> = chars.iter().take(4).map(|c| c.to_ascii_lowercase()).collect();
+    let mut chunk_size = cmp::min(3, lower.len());
+
+    while chunk_size > 0 {
+        let chunk = &lower[..chunk_size];
+
+        if chunk_size == 3 && lower.len() >= 4 && is_four_char_edgecase(chunk) {
+            if let Some(kana) = lookup(&lower[..4]) {
+                return (4, Some(kana));
+            }
+        } else if chunk[0] == 'n' {
+            if chunk_size == 2 && chunk[1] == ' ' {
+                return (2, Some("ん "));
+            }
+
+            if chunk_size == 3 && is_consonant(chunk[1], false) && is_vowel(chunk[2]) {
+                return (1, Some("ん"));
+            }
+        } else if chunk_size > 1 && is_consonant(chunk[0], true) &&
+                   (chunk[0] == chunk[1] || chunk == ['t', 'c', 'h'])
+        {
+            return (1, Some("っ"));
+        }
+
+        if let Some(kana) = lookup(chunk) {
+            return (chunk_size, Some(kana));
+        }
+
+        chunk_size -= 1;
+    }
+
+    (1, None)
+}
+
+fn lookup(chunk: &[char]) -> Option<&'static str> {
+    let chunk: String = chunk.iter().collect();
+    FROM_ROMAJI.get::<str>(chunk.as_ref()).cloned()
+}
+
+fn is_four_char_edgecase(chunk: &[char]) -> bool {
+    FOUR_CHAR_EDGECASES
+        .iter()
+        .any(|edgecase| edgecase.chars().eq(chunk.iter().cloned()))
+}
+
+/// `to_romaji` with the default options
+pub fn to_romaji(input: &str) -> String {
+    let hira: Vec<char> = katakana_to_hiragana(input).chars().collect();
+    let mut romaji = String::with_capacity(input.len());
+    let mut cursor = 0;
+    let mut double_next_consonant = false;
+
+    while cursor < hira.len() {
+        // Small tsu doubles the consonant of the following kana
+        if hira[cursor] == 'っ' && cursor + 1 < hira.len() {
+            double_next_consonant = true;
+            cursor += 1;
+            continue;
+        }
+
+        let (chunk_size, roma) = next_romaji(&hira[cursor..]);
+
+        match roma {
+            Some(roma) => {
+                if double_next_consonant {
+                    push_sokuon(&mut romaji, roma);
+                }
+                romaji.push_str(roma);
+            }
+            None => romaji.push(hira[cursor]),
+        }
+
+        double_next_consonant = false;
+        cursor += chunk_size;
+    }
+
+    romaji
+}
+
+fn next_romaji(chars: &[char]) -> (usize, Option<&'static str>) {
+    let mut chunk_size = cmp::min(2, chars.len());
+
+    while chunk_size > 0 {
+        let chunk: String = chars[..chunk_size].iter().collect();
+
+        if let Some(roma) = TO_ROMAJI.get::<str>(chunk.as_ref()) {
+            return (chunk_size, Some(roma));
+        }
+
+        chunk_size -= 1;
+    }
+
+    (1, None)
+}
+
+fn push_sokuon(romaji: &mut String, roma: &str) {
+    if roma.starts_with("ch") {
+        romaji.push('t');
+    } else if let Some(c) = roma.chars().next().filter(|&c| is_consonant(c, true)) {
+        romaji.push(c);
+    }
+}
+
+/// `katakana_to_hiragana` with the default options
+pub fn katakana_to_hiragana(input: &str) -> String {
+    let input = wanakana::halfwidth_to_katakana_cow(input);
+    let mut hira = String::with_capacity(input.len());
+
+    let mut previous_kana: Option<char> = None;
+
+    for (index, c) in input.chars().enumerate() {
+        let is_long_dash = c == PROLONGED_SOUND_MARK;
+
+        if c == KANA_SLASH_DOT || (is_long_dash && index < 1) {
+            hira.push(c);
+        } else if previous_kana.is_some() && is_long_dash {
+            // Transform previous_kana back to romaji, and slice off the vowel
+            let long_vowel = previous_kana
+                .and_then(|kana| TO_ROMAJI.get::<str>(kana.to_string().as_ref()))
+                .and_then(|romaji| romaji.chars().next_back())
+                .and_then(|vowel| LONG_VOWELS.get::<str>(vowel.to_string().as_ref()));
+
+            if let Some(vowel) = long_vowel {
+                hira.push_str(vowel);
+            }
+        } else if !is_long_dash && c.is_katakana() {
+            if let Some(hira_char) = ::std::char::from_u32(c as u32 - KATAKANA_TO_HIRAGANA_OFFSET) {
+                hira.push(hira_char);
+                previous_kana = Some(hira_char);
+            }
+        } else {
+            hira.push(c);
+            previous_kana = None;
+        }
+    }
+
+    hira
+}
@@ -0,0 +1,122 @@
+// The `is_*` predicates as they were before the char flag table, scanning a list of char ranges
+// for every char, kept as a baseline for the benchmarks of the table-backed predicates
+
+use std::ops::Range;
+
+type Ranges = &'static [Range<u32>];
+
+macro_rules! range {
+    ($start:expr, $end:expr) => {
+        Range { start: $start, end: ($end + 1), }
+    }
+}
+
+const CJK_SYMBOLS_PUNCTUATION: Range<u32> = range!(0x3000, 0x303F);
+const KATAKANA_PUNCTUATION: Range<u32> = range!(0x30FB, 0x30FC);
+const HIRAGANA_CHARS: Range<u32> = range!(0x3040, 0x309F);
+const KATAKANA_CHARS: Range<u32> = range!(0x30A0, 0x30FF);
+const ZENKAKU_NUMBERS: Range<u32> = range!(0xFF10, 0xFF19);
+const ZENKAKU_PUNCTUATION_1: Range<u32> = range!(0xFF01, 0xFF0F);
+const ZENKAKU_PUNCTUATION_2: Range<u32> = range!(0xFF1A, 0xFF1F);
+const ZENKAKU_PUNCTUATION_3: Range<u32> = range!(0xFF3B, 0xFF3F);
+const ZENKAKU_PUNCTUATION_4: Range<u32> = range!(0xFF5B, 0xFF60);
+const ZENKAKU_SYMBOLS_CURRENCY: Range<u32> = range!(0xFFE0, 0xFFEE);
+const KANA_PUNCTUATION: Range<u32> = range!(0xFF61, 0xFF65);
+const HANKAKU_KATAKANA: Range<u32> = range!(0xFF66, 0xFF9F);
+const COMMON_CJK: Range<u32> = range!(0x4E00, 0x9FFF);
+const RARE_CJK: Range<u32> = range!(0x3400, 0x4DBF);
+const LATIN_NUMBERS: Range<u32> = range!(0x0030, 0x0039);
+const MODERN_ENGLISH: Range<u32> = range!(0x0000, 0x007f);
+
+const KANJI_START: u32 = 0x4E00;
+const KANJI_END: u32 = 0x9FAF;
+
+const KANA_RANGES: Ranges = &[
+    HIRAGANA_CHARS,
+    KATAKANA_CHARS,
+    KANA_PUNCTUATION,
+    HANKAKU_KATAKANA,
+];
+
+const JAPANESE_RANGES: Ranges = &[
+    // KANA_RANGES
+    HIRAGANA_CHARS,
+    KATAKANA_CHARS,
+    KANA_PUNCTUATION,
+    HANKAKU_KATAKANA,
+
+    // JA_PUNCTUATION_RANGES
+    CJK_SYMBOLS_PUNCTUATION,
+    KANA_PUNCTUATION,
+    KATAKANA_PUNCTUATION,
+    ZENKAKU_PUNCTUATION_1,
+    ZENKAKU_PUNCTUATION_2,
+    ZENKAKU_PUNCTUATION_3,
+    ZENKAKU_PUNCTUATION_4,
+    ZENKAKU_SYMBOLS_CURRENCY,
+
+    // Other
+    LATIN_NUMBERS,
+    ZENKAKU_NUMBERS,
+    COMMON_CJK,
+    RARE_CJK,
+];
+
+const ROMAJI_RANGES: Ranges = &[
+    MODERN_ENGLISH,
+
+    // HEPBURN_MACRON_RANGES
+    range!(0x0100, 0x0101), // Ā ā
+    range!(0x0112, 0x0113), // Ē ē
+    range!(0x012a, 0x012b), // Ī ī
+    range!(0x014c, 0x014d), // Ō ō
+    range!(0x016a, 0x016b), // Ū ū
+
+    // KUNREI_CIRCUMFLEX_RANGES
+    range!(0x00c2, 0x00c2), // Â
+    range!(0x00ca, 0x00ca), // Ê
+    range!(0x00ce, 0x00ce), // Î
+    range!(0x00d4, 0x00d4), // Ô
+    range!(0x00db, 0x00db), // Û
+    range!(0x00e2, 0x00e2), // â
+    range!(0x00ea, 0x00ea), // ê
+    range!(0x00ee, 0x00ee), // î
+    range!(0x00f4, 0x00f4), // ô
+    range!(0x00fb, 0x00fb), // û
+
+    // SMART_QUOTE_RANGES
+    range!(0x2018, 0x2019), // ‘ ’
+    range!(0x201C, 0x201D), // “ ”
+];
+
+fn is_in_ranges(c: char, ranges: &[Range<u32>]) -> bool {
+    ranges.iter().any(|range| range.contains(&(c as u32)))
+}
+
+fn all_in_ranges(input: &str, ranges: &[Range<u32>]) -> bool {
+    input.chars().all(|c| is_in_ranges(c, ranges))
+}
+
+pub fn is_romaji(input: &str) -> bool {
+    all_in_ranges(input, ROMAJI_RANGES)
+}
+
+pub fn is_japanese(input: &str) -> bool {
+    all_in_ranges(input, JAPANESE_RANGES)
+}
+
+pub fn is_kana(input: &str) -> bool {
+    all_in_ranges(input, KANA_RANGES)
+}
+
+pub fn is_mixed(input: &str, pass_kanji: bool) -> bool {
+    let is_kanji = |c: char| KANJI_START <= (c as u32) && (c as u32) <= KANJI_END;
+    let has_kanji = if pass_kanji {
+        false
+    } else {
+        input.chars().any(is_kanji)
+    };
+
+    input.chars().any(|c| is_in_ranges(c, KANA_RANGES)) &&
+        input.chars().any(|c| is_in_ranges(c, ROMAJI_RANGES)) && !has_kanji
+}
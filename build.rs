@@ -1,36 +1,448 @@
 extern crate phf_codegen;
 
+use std::collections::BTreeMap;
 use std::env;
 use std::fs::File;
-use std::io::{BufWriter, Write};
+use std::io::{BufWriter, Read, Write};
+use std::ops::Range;
 use std::path::Path;
 
+// The hand-written char ranges of the crate, combined with the ranges derived from the UCD
+#[allow(dead_code)]
+mod ranges {
+    include!("src/ranges.rs");
+}
+
 macro_rules! define_map {
     (
         $writer:expr,
         $name:expr,
         $( ($from:expr, $to:expr), )+
     ) => {{
-        write!(
-            &mut $writer,
-            "pub(crate) static {}: ::phf::Map<&'static str, &'static str> = ",
-            $name
-        ).unwrap();
-
-        phf_codegen::Map::new().
-        $( entry($from, concat!('"', $to, '"')). )+
-        build(&mut $writer).unwrap();
+        let entries = vec![ $( ($from, $to), )+ ];
+        write_map(&mut $writer, $name, &entries);
+        entries
+    }}
+}
 
-        write!(&mut $writer, ";\n").unwrap();
+macro_rules! define_maps {
+    (
+        $writer:expr,
+        $name:expr,
+        $reverse_name:expr,
+        $( ($from:expr, $to:expr), )+
+    ) => {{
+        define_map!($writer, $name, $( ($from, $to), )+);
+        define_map!($writer, $reverse_name, $( ($to, $from), )+);
     }}
 }
 
+#[derive(Default)]
+struct TrieNode {
+    value: Option<&'static str>,
+    children: BTreeMap<char, usize>,
+}
+
+/// Write a phf map with the given entries
+fn write_map<W: Write>(writer: &mut W, name: &str, entries: &[(&'static str, &'static str)]) {
+    write!(writer, "pub(crate) static {}: ::phf::Map<&'static str, &'static str> = ", name)
+        .unwrap();
+
+    let mut map = phf_codegen::Map::new();
+    for &(key, value) in entries {
+        map.entry(key, &format!("{:?}", value));
+    }
+    map.build(writer).unwrap();
+
+    writeln!(writer, ";").unwrap();
+}
+
+/// Write a `Trie` (see src/trie.rs) with the same entries as a map, for longest-match lookups
+fn write_trie<W: Write>(writer: &mut W, name: &str, entries: &[(&'static str, &'static str)]) {
+    let mut nodes = vec![TrieNode::default()];
+
+    for &(key, value) in entries {
+        let mut node = 0;
+
+        for c in key.chars() {
+            node = match nodes[node].children.get(&c) {
+                Some(&child) => child,
+                None => {
+                    nodes.push(TrieNode::default());
+                    let child = nodes.len() - 1;
+                    nodes[node].children.insert(c, child);
+                    child
+                }
+            };
+        }
+
+        nodes[node].value = Some(value);
+    }
+
+    assert!(nodes.len() <= u16::MAX as usize, "{} has too many nodes", name);
+
+    writeln!(writer, "pub(crate) static {}: Trie = Trie(&[", name).unwrap();
+
+    for node in &nodes {
+        write!(writer, "    TrieNode {{ value: {:?}, children: &[", node.value).unwrap();
+
+        for (c, child) in &node.children {
+            write!(writer, "({:?}, {}), ", c, child).unwrap();
+        }
+
+        writeln!(writer, "] }},").unwrap();
+    }
+
+    writeln!(writer, "]);").unwrap();
+}
+
+/// Char properties from the vendored [Unicode Character Database](https://www.unicode.org/ucd/)
+/// files in ucd/. Upgrading Unicode means replacing them with the files of a newer release from
+/// unicode.org (see ucd/update.sh)
+struct Ucd {
+    version: (u8, u8, u8),
+    blocks: UcdEntries,
+    scripts: UcdEntries,
+    widths: UcdEntries,
+}
+
+/// The ranges of chars in a UCD file, with the value of the property for each range
+type UcdEntries = Vec<(Range<u32>, String)>;
+
+impl Ucd {
+    fn load(dir: &Path) -> Ucd {
+        let (version, blocks) = parse_ucd_file(&dir.join("Blocks.txt"));
+        let (scripts_version, scripts) = parse_ucd_file(&dir.join("Scripts.txt"));
+        let (widths_version, widths) = parse_ucd_file(&dir.join("EastAsianWidth.txt"));
+
+        assert!(
+            version == scripts_version && version == widths_version,
+            "the UCD files are from different versions of Unicode"
+        );
+
+        Ucd {
+            version,
+            blocks,
+            scripts,
+            widths,
+        }
+    }
+
+    /// The range of the block called `name`
+    fn block(&self, name: &str) -> Range<u32> {
+        self.blocks
+            .iter()
+            .find(|(_, block)| block == name)
+            .map(|(range, _)| range.clone())
+            .unwrap_or_else(|| panic!("no block named {}", name))
+    }
+
+    /// The script of the char `code`, e.g. "Hiragana"
+    fn script(&self, code: u32) -> &str {
+        property(&self.scripts, code).unwrap_or("Unknown")
+    }
+
+    /// The East Asian Width of the char `code`, e.g. "H" for half-width
+    fn width(&self, code: u32) -> &str {
+        property(&self.widths, code).unwrap_or("N")
+    }
+
+    /// The runs of chars of `script` in `range`
+    fn script_runs(&self, range: &Range<u32>, script: &str) -> Vec<Range<u32>> {
+        let mut entries: Vec<Range<u32>> = self
+            .scripts
+            .iter()
+            .filter(|(_, value)| value == script)
+            .map(|(entry, _)| entry.start.max(range.start)..entry.end.min(range.end))
+            .filter(|entry| entry.start < entry.end)
+            .collect();
+        entries.sort_by_key(|entry| entry.start);
+
+        let mut runs: Vec<Range<u32>> = Vec::new();
+        for entry in entries {
+            match runs.last_mut() {
+                Some(run) if run.end == entry.start => run.end = entry.end,
+                _ => runs.push(entry),
+            }
+        }
+        runs
+    }
+
+    /// The first run of chars of `script` in `range`
+    fn script_run(&self, range: Range<u32>, script: &str) -> Range<u32> {
+        let start = range
+            .clone()
+            .find(|&code| self.script(code) == script)
+            .unwrap_or_else(|| panic!("no {} chars in {:?}", script, range));
+        let end = (start..range.end)
+            .find(|&code| self.script(code) != script)
+            .unwrap_or(range.end);
+
+        start..end
+    }
+}
+
+fn property(entries: &UcdEntries, code: u32) -> Option<&str> {
+    entries
+        .iter()
+        .find(|(range, _)| range.contains(&code))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Parse the version (from the header, e.g. "# Blocks-14.0.0.txt") and the `range; value` lines
+/// of a UCD file, followed by the defaults for unlisted chars from its `# @missing:` lines
+fn parse_ucd_file(path: &Path) -> ((u8, u8, u8), UcdEntries) {
+    let mut contents = String::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut contents))
+        .unwrap_or_else(|err| panic!("failed to read {}: {}", path.display(), err));
+
+    let header = contents.lines().next().unwrap_or("");
+    let version: Vec<u8> = header
+        .trim_end_matches(".txt")
+        .rsplit('-')
+        .next()
+        .unwrap()
+        .split('.')
+        .map(|part| part.parse().expect("invalid UCD version"))
+        .collect();
+    assert_eq!(version.len(), 3, "no version in the header of {}", path.display());
+
+    let parse_code = |code: &str| u32::from_str_radix(code.trim(), 16).unwrap();
+    let parse_entry = |line: &str| {
+        let mut fields = line.split(';');
+        let codes = fields.next().unwrap();
+        let value = fields.next().expect("missing UCD value").trim().to_string();
+
+        let range = match codes.find("..") {
+            Some(index) => parse_code(&codes[..index])..parse_code(&codes[index + 2..]) + 1,
+            None => parse_code(codes)..parse_code(codes) + 1,
+        };
+
+        (range, value)
+    };
+
+    let mut entries: UcdEntries = contents
+        .lines()
+        .map(|line| line.split('#').next().unwrap().trim())
+        .filter(|line| !line.is_empty())
+        .map(parse_entry)
+        .collect();
+
+    // Later `@missing` lines override earlier ones for the chars they cover, so they're searched
+    // first (e.g. "3400..4DBF; W" after "0000..10FFFF; N" in EastAsianWidth.txt)
+    let mut missing: UcdEntries = contents
+        .lines()
+        .filter_map(|line| line.trim_start_matches('#').trim().strip_prefix("@missing:"))
+        .map(|line| parse_entry(line.split('#').next().unwrap()))
+        .collect();
+    missing.reverse();
+    entries.extend(missing);
+
+    ((version[0], version[1], version[2]), entries)
+}
+
+fn write_range<W: Write>(writer: &mut W, name: &str, range: &Range<u32>) {
+    writeln!(
+        writer,
+        "pub(crate) const {}: Range<u32> = Range {{ start: {:#06X}, end: {:#06X} }};",
+        name,
+        range.start,
+        range.end
+    ).unwrap();
+}
+
+fn write_ranges<W: Write>(writer: &mut W, name: &str, ranges: &[Range<u32>]) {
+    write!(writer, "pub(crate) const {}: Ranges = &[", name).unwrap();
+    for range in ranges {
+        write!(writer, "Range {{ start: {:#06X}, end: {:#06X} }}, ", range.start, range.end)
+            .unwrap();
+    }
+    writeln!(writer, "];").unwrap();
+}
+
+/// Write the ranges derived from the UCD, returning the ranges of each class of chars tested by
+/// the predicates in src/is.rs
+fn write_char_ranges<W: Write>(writer: &mut W, ucd: &Ucd) -> Vec<(&'static str, Vec<Range<u32>>)> {
+    use ranges::*;
+
+    writeln!(writer, "pub(crate) const UCD_VERSION: (u8, u8, u8) = {:?};", ucd.version).unwrap();
+
+    let hiragana_chars = ucd.block("Hiragana");
+    let katakana_chars = ucd.block("Katakana");
+    let common_cjk = ucd.block("CJK Unified Ideographs");
+    let rare_cjk = ucd.block("CJK Unified Ideographs Extension A");
+    let cjk_symbols_punctuation = ucd.block("CJK Symbols and Punctuation");
+    let modern_english = ucd.block("Basic Latin");
+
+    let hiragana = ucd.script_run(hiragana_chars.clone(), "Hiragana");
+    let katakana_start = ucd.script_run(katakana_chars.clone(), "Katakana").start;
+    let kanji = ucd.script_runs(&common_cjk, "Han");
+
+    // Ideographs outside of the BMP, e.g. "𠮷" (Extensions B and later), and the compatibility
+    // ideographs, e.g. "﨑", which are mostly duplicates kept for round-tripping older encodings
+    let mut supplementary_cjk: Vec<_> = ucd
+        .blocks
+        .iter()
+        .filter(|(range, block)| {
+            block.starts_with("CJK Unified Ideographs Extension ") && range.start > 0xFFFF
+        })
+        .flat_map(|(range, _)| ucd.script_runs(range, "Han"))
+        .collect();
+    for (name, range) in LATER_CJK_EXTENSIONS {
+        assert!(
+            !ucd.blocks.iter().any(|(_, block)| block == name),
+            "ucd/ already has the {} block, so remove LATER_CJK_EXTENSIONS from src/ranges.rs",
+            name
+        );
+        supplementary_cjk.push(range.clone());
+    }
+    supplementary_cjk.sort_by_key(|range| range.start);
+    let compatibility_blocks = [
+        "CJK Compatibility Ideographs",
+        "CJK Compatibility Ideographs Supplement",
+    ];
+    let compatibility_cjk: Vec<_> = compatibility_blocks
+        .iter()
+        .flat_map(|name| ucd.script_runs(&ucd.block(name), "Han"))
+        .collect();
+
+    // Half-width Katakana, followed by the half-width voicing marks and preceded by the
+    // half-width Japanese punctuation
+    let halfwidth_forms = ucd.block("Halfwidth and Fullwidth Forms");
+    let is_halfwidth = |code: u32, script: &str| {
+        ucd.width(code) == "H" && ucd.script(code) == script
+    };
+    let hankaku_start = ucd.script_run(halfwidth_forms.clone(), "Katakana").start;
+    let hankaku_kana_end = (hankaku_start..halfwidth_forms.end)
+        .rfind(|&code| is_halfwidth(code, "Katakana"))
+        .unwrap();
+    let hankaku_end = (hankaku_kana_end + 1..halfwidth_forms.end)
+        .find(|&code| !is_halfwidth(code, "Common"))
+        .unwrap();
+    let kana_punctuation_start = (halfwidth_forms.start..hankaku_start)
+        .rev()
+        .find(|&code| !is_halfwidth(code, "Common"))
+        .unwrap() + 1;
+    let hankaku_katakana = hankaku_start..hankaku_end;
+    let kana_punctuation = kana_punctuation_start..hankaku_start;
+
+    write_range(writer, "HIRAGANA_CHARS", &hiragana_chars);
+    write_range(writer, "KATAKANA_CHARS", &katakana_chars);
+    write_range(writer, "COMMON_CJK", &common_cjk);
+    write_range(writer, "RARE_CJK", &rare_cjk);
+    write_range(writer, "KANA_PUNCTUATION", &kana_punctuation);
+    write_range(writer, "HANKAKU_KATAKANA", &hankaku_katakana);
+    writeln!(writer, "pub(crate) const HIRAGANA_START: u32 = {:#06X};", hiragana.start).unwrap();
+    writeln!(writer, "pub(crate) const HIRAGANA_END: u32 = {:#06X};", hiragana.end - 1).unwrap();
+    writeln!(writer, "pub(crate) const KATAKANA_START: u32 = {:#06X};", katakana_start).unwrap();
+    write_ranges(writer, "SUPPLEMENTARY_CJK_RANGES", &supplementary_cjk);
+    write_ranges(writer, "COMPATIBILITY_CJK_RANGES", &compatibility_cjk);
+
+    let mut ja_punctuation = vec![cjk_symbols_punctuation, kana_punctuation.clone()];
+    ja_punctuation.extend_from_slice(FULLWIDTH_PUNCTUATION_RANGES);
+    write_ranges(writer, "JA_PUNCTUATION_RANGES", &ja_punctuation);
+
+    let kana = vec![hiragana_chars, katakana_chars, kana_punctuation, hankaku_katakana];
+
+    // Japanese includes full-width punctuation and numbers, and latin numbers since they are used
+    // in Japanese text as well
+    let mut japanese = kana.clone();
+    japanese.extend(ja_punctuation);
+    japanese.extend(vec![LATIN_NUMBERS, ZENKAKU_NUMBERS, common_cjk, rare_cjk.clone()]);
+    japanese.extend(supplementary_cjk.iter().cloned());
+    japanese.extend(compatibility_cjk.iter().cloned());
+
+    // Kanji covers every ideograph by default, and the marks used like Kanji, see `KanjiOptions`
+    // in src/is.rs
+    let mut kanji = kanji;
+    kanji.push(rare_cjk);
+    kanji.extend(supplementary_cjk);
+    kanji.extend(compatibility_cjk);
+    kanji.extend_from_slice(KANJI_MARK_RANGES);
+
+    // Romaji includes Hepburn macrons, Kunrei-shiki circumflexes and smart quotes
+    let mut romaji = vec![modern_english];
+    romaji.extend_from_slice(ROMAJI_MARK_RANGES);
+    romaji.extend_from_slice(SMART_QUOTE_RANGES);
+
+    vec![
+        (
+            "HIRAGANA",
+            vec![PROLONGED_SOUND_MARK..PROLONGED_SOUND_MARK + 1, hiragana],
+        ),
+        ("KATAKANA", vec![Range { start: katakana_start, end: KATAKANA_END + 1 }]),
+        ("KANA", kana),
+        ("ROMAJI", romaji),
+        ("KANJI", kanji),
+        ("JAPANESE", japanese),
+    ]
+}
+
+/// Write the flags of every char as a two-level table: `CHAR_FLAG_INDEX` maps the upper bits of
+/// a char to one of the distinct 256-char blocks of flags in `CHAR_FLAG_BLOCKS`, where each flag
+/// is one of `classes`
+fn write_char_flags<W: Write>(writer: &mut W, classes: &[(&str, Vec<Range<u32>>)]) {
+    assert!(classes.len() <= 8, "too many char classes for u8 flags");
+
+    for (bit, &(name, _)) in classes.iter().enumerate() {
+        writeln!(writer, "pub(crate) const {}_FLAG: u8 = 1 << {};", name, bit).unwrap();
+    }
+
+    let mut blocks: Vec<Vec<u8>> = Vec::new();
+    let mut index = Vec::new();
+
+    for upper in 0..(char::MAX as u32 >> 8) + 1 {
+        let block: Vec<u8> = (0..256)
+            .map(|lower| {
+                let code = upper << 8 | lower;
+                classes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (_, ranges))| ranges.iter().any(|r| r.contains(&code)))
+                    .fold(0, |flags, (bit, _)| flags | 1 << bit)
+            })
+            .collect();
+
+        let position = match blocks.iter().position(|existing| *existing == block) {
+            Some(position) => position,
+            None => {
+                blocks.push(block);
+                blocks.len() - 1
+            }
+        };
+        index.push(position);
+    }
+
+    assert!(blocks.len() <= u8::MAX as usize, "too many char flag blocks");
+
+    let ascii_flags = blocks[index[0]][..0x80].iter().fold(!0, |flags, &c| flags & c);
+    writeln!(writer, "pub(crate) const ASCII_FLAGS: u8 = {};", ascii_flags).unwrap();
+
+    writeln!(writer, "pub(crate) static CHAR_FLAG_INDEX: [u8; {}] = {:?};", index.len(), index)
+        .unwrap();
+    writeln!(
+        writer,
+        "pub(crate) static CHAR_FLAG_BLOCKS: [[u8; 256]; {}] = {:?};",
+        blocks.len(),
+        blocks
+    ).unwrap();
+}
+
 fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/ranges.rs");
+    println!("cargo:rerun-if-changed=ucd");
+
     let path = Path::new(&env::var("OUT_DIR").unwrap()).join("constants.rs");
     let file = File::create(&path).unwrap();
     let mut writer = BufWriter::new(file);
 
-    define_map!(
+    let ucd = Ucd::load(&Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("ucd"));
+    let classes = write_char_ranges(&mut writer, &ucd);
+    write_char_flags(&mut writer, &classes);
+
+    let long_vowels = define_map!(
         writer,
         "LONG_VOWELS",
         ("a", "あ"),
@@ -40,9 +452,39 @@ fn main() {
         ("o", "う"),
     );
 
-    define_map!(
+    define_maps!(
         writer,
-        "FROM_ROMAJI",
+        "MACRON_VOWELS",
+        "FROM_MACRON_VOWELS",
+        ("a", "ā"),
+        ("i", "ī"),
+        ("u", "ū"),
+        ("e", "ē"),
+        ("o", "ō"),
+        ("A", "Ā"),
+        ("I", "Ī"),
+        ("U", "Ū"),
+        ("E", "Ē"),
+        ("O", "Ō"),
+    );
+
+    define_maps!(
+        writer,
+        "CIRCUMFLEX_VOWELS",
+        "FROM_CIRCUMFLEX_VOWELS",
+        ("a", "â"),
+        ("i", "î"),
+        ("u", "û"),
+        ("e", "ê"),
+        ("o", "ô"),
+        ("A", "Â"),
+        ("I", "Î"),
+        ("U", "Û"),
+        ("E", "Ê"),
+        ("O", "Ô"),
+    );
+
+    let from_romaji = vec![
         (".", "。"),
         (",", "、"),
         (":", "："),
@@ -99,10 +541,10 @@ fn main() {
         ("ku", "く"),
         ("ke", "け"),
         ("ko", "こ"),
-        ("lka", "ヵ"),
-        ("lke", "ヶ"),
-        ("xka", "ヵ"),
-        ("xke", "ヶ"),
+        ("lka", "ゕ"),
+        ("lke", "ゖ"),
+        ("xka", "ゕ"),
+        ("xke", "ゖ"),
         ("kya", "きゃ"),
         ("kyi", "きぃ"),
         ("kyu", "きゅ"),
@@ -113,10 +555,10 @@ fn main() {
         ("cu", "く"),
         ("ce", "け"),
         ("co", "こ"),
-        ("lca", "ヵ"),
-        ("lce", "ヶ"),
-        ("xca", "ヵ"),
-        ("xce", "ヶ"),
+        ("lca", "ゕ"),
+        ("lce", "ゖ"),
+        ("xca", "ゕ"),
+        ("xce", "ゖ"),
         ("qya", "くゃ"),
         ("qyu", "くゅ"),
         ("qyo", "くょ"),
@@ -345,6 +787,8 @@ fn main() {
         ("lyo", "りょ"),
         ("wa", "わ"),
         ("wo", "を"),
+        ("wyi", "ゐ"),
+        ("wye", "ゑ"),
         ("lwe", "ゎ"),
         ("xwa", "ゎ"),
         ("n", "ん"),
@@ -353,12 +797,10 @@ fn main() {
         ("n ", "ん"), // n + space
         ("xn", "ん"),
         ("ltsu", "っ"),  // 4 character code
-    );
-
-    define_map!(
-        writer,
-        "TO_ROMAJI",
+    ];
+    write_trie(&mut writer, "FROM_ROMAJI_TRIE", &from_romaji);
 
+    let to_romaji = vec![
         ("　", " "),
         ("！", "!"),
         ("？", "?"),
@@ -603,5 +1045,205 @@ fn main() {
         ("んや", "n'ya"),
         ("んゆ", "n'yu"),
         ("んよ", "n'yo"),
+    ];
+    write_trie(&mut writer, "TO_ROMAJI_TRIE", &to_romaji);
+
+    // The maps replaced by the tries, for the baseline in benches/phf_lookup
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("phf_lookup.rs");
+    let mut baseline_writer = BufWriter::new(File::create(&path).unwrap());
+    write_map(&mut baseline_writer, "LONG_VOWELS", &long_vowels);
+    write_map(&mut baseline_writer, "FROM_ROMAJI", &from_romaji);
+    write_map(&mut baseline_writer, "TO_ROMAJI", &to_romaji);
+
+    // Kunrei-shiki entries that differ from the (Hepburn) TO_ROMAJI_TRIE
+    let kunrei_to_romaji = vec![
+        ("し", "si"),
+        ("しゃ", "sya"),
+        ("しゅ", "syu"),
+        ("しょ", "syo"),
+        ("しぇ", "sye"),
+        ("じ", "zi"),
+        ("じゃ", "zya"),
+        ("じゅ", "zyu"),
+        ("じょ", "zyo"),
+        ("じぃ", "zyi"),
+        ("じぇ", "zye"),
+        ("ち", "ti"),
+        ("ちゃ", "tya"),
+        ("ちゅ", "tyu"),
+        ("ちょ", "tyo"),
+        ("ちぃ", "tyi"),
+        ("ちぇ", "tye"),
+        ("つ", "tu"),
+        ("ふ", "hu"),
+        ("ぢ", "zi"),
+        ("ぢゃ", "zya"),
+        ("ぢぃ", "zyi"),
+        ("ぢゅ", "zyu"),
+        ("ぢぇ", "zye"),
+        ("ぢょ", "zyo"),
+        ("づ", "zu"),
+        ("を", "o"),
+        ("ゐ", "i"),
+        ("ゑ", "e"),
+    ];
+    write_trie(&mut writer, "KUNREI_TO_ROMAJI_TRIE", &kunrei_to_romaji);
+
+    // Nihon-shiki entries that differ from the (Hepburn) TO_ROMAJI_TRIE
+    let nihon_to_romaji = vec![
+        ("し", "si"),
+        ("しゃ", "sya"),
+        ("しゅ", "syu"),
+        ("しょ", "syo"),
+        ("しぇ", "sye"),
+        ("じ", "zi"),
+        ("じゃ", "zya"),
+        ("じゅ", "zyu"),
+        ("じょ", "zyo"),
+        ("じぃ", "zyi"),
+        ("じぇ", "zye"),
+        ("ち", "ti"),
+        ("ちゃ", "tya"),
+        ("ちゅ", "tyu"),
+        ("ちょ", "tyo"),
+        ("ちぃ", "tyi"),
+        ("ちぇ", "tye"),
+        ("つ", "tu"),
+        ("ふ", "hu"),
+        ("ぢ", "di"),
+        ("ぢゃ", "dya"),
+        ("ぢぃ", "dyi"),
+        ("ぢゅ", "dyu"),
+        ("ぢぇ", "dye"),
+        ("ぢょ", "dyo"),
+        ("づ", "du"),
+        ("を", "wo"),
+        ("ゐ", "wi"),
+        ("ゑ", "we"),
+        ("くゎ", "kwa"),
+        ("ぐゎ", "gwa"),
+    ];
+    write_trie(&mut writer, "NIHON_TO_ROMAJI_TRIE", &nihon_to_romaji);
+
+    // Reversible entries that differ from the (Hepburn) TO_ROMAJI_TRIE, so that every kana can
+    // be converted back with FROM_ROMAJI_TRIE
+    let reversible_to_romaji = vec![
+        ("　", "　"),
+        ("ぁ", "xa"),
+        ("ぃ", "xi"),
+        ("ぅ", "xu"),
+        ("ぇ", "xe"),
+        ("ぉ", "xo"),
+        ("ゃ", "xya"),
+        ("ゅ", "xyu"),
+        ("ょ", "xyo"),
+        ("っ", "xtu"),
+        ("ゕ", "xka"),
+        ("ゖ", "xke"),
+        ("ゎ", "xwa"),
+        ("ゐ", "wyi"),
+        ("ゑ", "wye"),
+        ("くゎ", "kuxwa"),
+        ("ぐゎ", "guxwa"),
+    ];
+    write_trie(&mut writer, "REVERSIBLE_TO_ROMAJI_TRIE", &reversible_to_romaji);
+
+    define_maps!(
+        writer,
+        "HANKAKU_TO_ZENKAKU_KATAKANA",
+        "ZENKAKU_TO_HANKAKU_KATAKANA",
+        ("｡", "。"),
+        ("｢", "「"),
+        ("｣", "」"),
+        ("､", "、"),
+        ("･", "・"),
+        ("ｦ", "ヲ"),
+        ("ｧ", "ァ"),
+        ("ｨ", "ィ"),
+        ("ｩ", "ゥ"),
+        ("ｪ", "ェ"),
+        ("ｫ", "ォ"),
+        ("ｬ", "ャ"),
+        ("ｭ", "ュ"),
+        ("ｮ", "ョ"),
+        ("ｯ", "ッ"),
+        ("ｰ", "ー"),
+        ("ｱ", "ア"),
+        ("ｲ", "イ"),
+        ("ｳ", "ウ"),
+        ("ｴ", "エ"),
+        ("ｵ", "オ"),
+        ("ｶ", "カ"),
+        ("ｷ", "キ"),
+        ("ｸ", "ク"),
+        ("ｹ", "ケ"),
+        ("ｺ", "コ"),
+        ("ｻ", "サ"),
+        ("ｼ", "シ"),
+        ("ｽ", "ス"),
+        ("ｾ", "セ"),
+        ("ｿ", "ソ"),
+        ("ﾀ", "タ"),
+        ("ﾁ", "チ"),
+        ("ﾂ", "ツ"),
+        ("ﾃ", "テ"),
+        ("ﾄ", "ト"),
+        ("ﾅ", "ナ"),
+        ("ﾆ", "ニ"),
+        ("ﾇ", "ヌ"),
+        ("ﾈ", "ネ"),
+        ("ﾉ", "ノ"),
+        ("ﾊ", "ハ"),
+        ("ﾋ", "ヒ"),
+        ("ﾌ", "フ"),
+        ("ﾍ", "ヘ"),
+        ("ﾎ", "ホ"),
+        ("ﾏ", "マ"),
+        ("ﾐ", "ミ"),
+        ("ﾑ", "ム"),
+        ("ﾒ", "メ"),
+        ("ﾓ", "モ"),
+        ("ﾔ", "ヤ"),
+        ("ﾕ", "ユ"),
+        ("ﾖ", "ヨ"),
+        ("ﾗ", "ラ"),
+        ("ﾘ", "リ"),
+        ("ﾙ", "ル"),
+        ("ﾚ", "レ"),
+        ("ﾛ", "ロ"),
+        ("ﾜ", "ワ"),
+        ("ﾝ", "ン"),
+        ("ﾞ", "゛"),
+        ("ﾟ", "゜"),
+
+        // Voiced and semi-voiced sounds
+        ("ｶﾞ", "ガ"),
+        ("ｷﾞ", "ギ"),
+        ("ｸﾞ", "グ"),
+        ("ｹﾞ", "ゲ"),
+        ("ｺﾞ", "ゴ"),
+        ("ｻﾞ", "ザ"),
+        ("ｼﾞ", "ジ"),
+        ("ｽﾞ", "ズ"),
+        ("ｾﾞ", "ゼ"),
+        ("ｿﾞ", "ゾ"),
+        ("ﾀﾞ", "ダ"),
+        ("ﾁﾞ", "ヂ"),
+        ("ﾂﾞ", "ヅ"),
+        ("ﾃﾞ", "デ"),
+        ("ﾄﾞ", "ド"),
+        ("ﾊﾞ", "バ"),
+        ("ﾊﾟ", "パ"),
+        ("ﾋﾞ", "ビ"),
+        ("ﾋﾟ", "ピ"),
+        ("ﾌﾞ", "ブ"),
+        ("ﾌﾟ", "プ"),
+        ("ﾍﾞ", "ベ"),
+        ("ﾍﾟ", "ペ"),
+        ("ﾎﾞ", "ボ"),
+        ("ﾎﾟ", "ポ"),
+        ("ｳﾞ", "ヴ"),
+        ("ﾜﾞ", "ヷ"),
+        ("ｦﾞ", "ヺ"),
     );
 }
@@ -1,7 +1,8 @@
 use constants;
+use is::KanjiOptions;
 use std::ops::Range;
 
-fn is_between(c: char, lower: u32, upper: u32) -> bool {
+pub(crate) fn is_between(c: char, lower: u32, upper: u32) -> bool {
     lower <= (c as u32) && (c as u32) <= upper
 }
 
@@ -9,18 +10,87 @@ pub(crate) fn is_in_ranges(c: char, ranges: &[Range<u32>]) -> bool {
     ranges.iter().any(|range| is_in_range(c, range))
 }
 
-fn is_in_range(c: char, range: &Range<u32>) -> bool {
-    is_between(c, range.start, range.end)
+pub(crate) fn is_in_range(c: char, range: &Range<u32>) -> bool {
+    range.contains(&(c as u32))
 }
 
-pub(crate) trait CharExt {
+/// Look up the flags of `c` (e.g. `constants::KANA_FLAG`) in the table generated by the build
+/// script
+pub(crate) fn char_flags(c: char) -> u8 {
+    let code = c as usize;
+    let block = constants::CHAR_FLAG_INDEX[code >> 8] as usize;
+    constants::CHAR_FLAG_BLOCKS[block][code & 0xFF]
+}
+
+/// Test if every char of `input` has `flag`, scanning the bytes directly if `input` is ASCII
+pub(crate) fn all_have_flag(input: &str, flag: u8) -> bool {
+    if input.is_ascii() {
+        // Every ASCII char has the flags in `ASCII_FLAGS` (e.g. `ROMAJI_FLAG`)
+        if constants::ASCII_FLAGS & flag != 0 {
+            return true;
+        }
+
+        let ascii = &constants::CHAR_FLAG_BLOCKS[constants::CHAR_FLAG_INDEX[0] as usize];
+        input.bytes().all(|byte| ascii[byte as usize] & flag != 0)
+    } else {
+        input.chars().all(|c| char_flags(c) & flag != 0)
+    }
+}
+
+/// Combine the flags of every char of `input`, scanning the bytes directly if `input` is ASCII
+pub(crate) fn any_flags(input: &str) -> u8 {
+    if input.is_ascii() {
+        let ascii = &constants::CHAR_FLAG_BLOCKS[constants::CHAR_FLAG_INDEX[0] as usize];
+        input.bytes().fold(0, |flags, byte| flags | ascii[byte as usize])
+    } else {
+        input.chars().fold(0, |flags, c| flags | char_flags(c))
+    }
+}
+
+/// Predicates for testing the script of a single char, like the `is_*` functions for strings
+///
+/// ```rust
+/// # use wanakana::CharExt;
+/// assert!('あ'.is_hiragana());
+/// assert!('ア'.is_katakana());
+/// assert!('ｱ'.is_kana());
+/// assert!('ō'.is_romaji());
+/// assert!('腹'.is_kanji());
+/// assert!('！'.is_japanese());
+/// assert!(!'A'.is_japanese());
+/// ```
+#[allow(clippy::wrong_self_convention)]
+pub trait CharExt {
+    /// Test if the char is [Hiragana](https://en.wikipedia.org/wiki/Hiragana), see
+    /// [`is_hiragana`](fn.is_hiragana.html)
     fn is_hiragana(self) -> bool;
+
+    /// Test if the char is [Katakana](https://en.wikipedia.org/wiki/Katakana), see
+    /// [`is_katakana`](fn.is_katakana.html)
     fn is_katakana(self) -> bool;
+
+    /// Test if the char is [Kana](https://en.wikipedia.org/wiki/Kana), see
+    /// [`is_kana`](fn.is_kana.html)
     fn is_kana(self) -> bool;
+
+    /// Test if the char is [Romaji](https://en.wikipedia.org/wiki/Romaji), see
+    /// [`is_romaji`](fn.is_romaji.html)
     fn is_romaji(self) -> bool;
+
+    /// Test if the char is [Kanji](https://en.wikipedia.org/wiki/Kanji), see
+    /// [`is_kanji`](fn.is_kanji.html)
     fn is_kanji(self) -> bool;
+
+    /// Test if the char is [Kanji](https://en.wikipedia.org/wiki/Kanji), counting the chars
+    /// chosen by `options`, see [`is_kanji_with_options`](fn.is_kanji_with_options.html)
+    fn is_kanji_with_options(self, options: &KanjiOptions) -> bool;
+
+    /// Test if the char is Japanese, see [`is_japanese`](fn.is_japanese.html)
+    fn is_japanese(self) -> bool;
 }
 
+// Hiragana and Katakana are single ranges, which are quicker to test directly than to look
+// up in the char flag table
 impl CharExt for char {
     fn is_hiragana(self) -> bool {
         (self as u32) == constants::PROLONGED_SOUND_MARK ||
@@ -32,14 +102,37 @@ impl CharExt for char {
     }
 
     fn is_kana(self) -> bool {
-        is_in_ranges(self, constants::KANA_RANGES)
+        char_flags(self) & constants::KANA_FLAG != 0
     }
 
     fn is_romaji(self) -> bool {
-        is_in_ranges(self, constants::ROMAJI_RANGES)
+        char_flags(self) & constants::ROMAJI_FLAG != 0
     }
 
     fn is_kanji(self) -> bool {
-        is_between(self, constants::KANJI_START, constants::KANJI_END)
+        char_flags(self) & constants::KANJI_FLAG != 0
+    }
+
+    fn is_kanji_with_options(self, options: &KanjiOptions) -> bool {
+        options.includes(self)
+    }
+
+    fn is_japanese(self) -> bool {
+        char_flags(self) & constants::JAPANESE_FLAG != 0
+    }
+}
+
+/// Test if `c` is one of the romaji vowels `aeiou` (case-insensitive)
+pub(crate) fn is_vowel(c: char) -> bool {
+    matches!(c.to_ascii_lowercase(), 'a' | 'i' | 'u' | 'e' | 'o')
+}
+
+/// Test if `c` is a romaji consonant (case-insensitive), optionally counting `y` as one
+pub(crate) fn is_consonant(c: char, include_y: bool) -> bool {
+    match c.to_ascii_lowercase() {
+        'y' => include_y,
+        'b' | 'c' | 'd' | 'f' | 'g' | 'h' | 'j' | 'k' | 'l' | 'm' | 'n' | 'p' | 'q' | 'r' |
+        's' | 't' | 'v' | 'w' | 'x' | 'z' => true,
+        _ => false,
     }
 }
@@ -0,0 +1,93 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+/// A set of chars, made of single chars, ranges of chars and closures, e.g. for the chars
+/// [`AllowedChars`](struct.AllowedChars.html) lets through the `is_*_allowing` predicates
+///
+/// ```rust
+/// # use wanakana::CharSet;
+/// let mut set = CharSet::new();
+/// set.insert('-').insert_str("・、").insert_range('0'..='9').insert_fn(char::is_whitespace);
+///
+/// assert!(set.contains('-'));
+/// assert!(set.contains('、'));
+/// assert!(set.contains('7'));
+/// assert!(set.contains('　'));
+/// assert!(!set.contains('a'));
+///
+/// let set: CharSet = "!?".chars().collect();
+/// assert!(set.contains('?'));
+/// ```
+#[derive(Clone, Default)]
+pub struct CharSet {
+    chars: Vec<char>,
+    ranges: Vec<RangeInclusive<char>>,
+    predicates: Vec<Arc<dyn Fn(char) -> bool + Send + Sync>>,
+}
+
+impl CharSet {
+    /// Create an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the char `c`
+    pub fn insert(&mut self, c: char) -> &mut Self {
+        self.chars.push(c);
+        self
+    }
+
+    /// Add every char of `chars`
+    pub fn insert_str(&mut self, chars: &str) -> &mut Self {
+        self.chars.extend(chars.chars());
+        self
+    }
+
+    /// Add the chars in `range`, e.g. `'ぁ'..='ゖ'`
+    pub fn insert_range(&mut self, range: RangeInclusive<char>) -> &mut Self {
+        self.ranges.push(range);
+        self
+    }
+
+    /// Add the chars `predicate` returns `true` for, e.g. `char::is_whitespace`
+    pub fn insert_fn<F>(&mut self, predicate: F) -> &mut Self
+    where
+        F: Fn(char) -> bool + Send + Sync + 'static,
+    {
+        self.predicates.push(Arc::new(predicate));
+        self
+    }
+
+    /// Test if `c` is in this set
+    pub fn contains(&self, c: char) -> bool {
+        self.chars.contains(&c) ||
+            self.ranges.iter().any(|range| range.contains(&c)) ||
+            self.predicates.iter().any(|predicate| predicate(c))
+    }
+
+    /// Test if nothing has been added to this set
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty() && self.ranges.is_empty() && self.predicates.is_empty()
+    }
+}
+
+impl fmt::Debug for CharSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CharSet")
+            .field("chars", &self.chars)
+            .field("ranges", &self.ranges)
+            .field("predicates", &self.predicates.len())
+            .finish()
+    }
+}
+
+impl FromIterator<char> for CharSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        CharSet {
+            chars: iter.into_iter().collect(),
+            ..CharSet::default()
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use char_ext::{self, CharExt};
+use constants;
+
+/// The version of the [Unicode Character Database](https://www.unicode.org/ucd/) that char
+/// classification is generated from
+///
+/// The script ranges used by [`classify`](fn.classify.html) and the `is_*` predicates are derived
+/// at build time from the UCD files vendored in the crate's ucd/ directory.
+///
+/// ```rust
+/// assert_eq!(wanakana::UNICODE_VERSION, (14, 0, 0));
+/// ```
+pub const UNICODE_VERSION: (u8, u8, u8) = constants::UCD_VERSION;
+
+/// The class of a single char, see [`classify`](fn.classify.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CharClass {
+    /// [Hiragana](https://en.wikipedia.org/wiki/Hiragana), e.g. "あ", "ゃ" or "が"
+    Hiragana(KanaKind),
+    /// Full-width [Katakana](https://en.wikipedia.org/wiki/Katakana), e.g. "ア", "ャ" or "ガ"
+    Katakana(KanaKind),
+    /// [Half-width Katakana](https://en.wikipedia.org/wiki/Half-width_kana), e.g. "ｱ" or "ｬ"
+    HalfwidthKatakana(KanaKind),
+    /// The [dakuten and handakuten](https://en.wikipedia.org/wiki/Dakuten_and_handakuten) voicing
+    /// marks when written as separate chars, e.g. "゛" or "ﾞ"
+    VoicingMark,
+    /// The [prolonged sound mark](https://en.wikipedia.org/wiki/Ch%C5%8Donpu) "ー" (or "ｰ")
+    ProlongedSoundMark,
+    /// An [iteration mark](https://en.wikipedia.org/wiki/Iteration_mark), repeating a char of the
+    /// given script, e.g. "ゝ" (Hiragana), "ヾ" (Katakana) or "々" (Kanji)
+    IterationMark(Script),
+    /// [Kanji](https://en.wikipedia.org/wiki/Kanji), e.g. "漢"
+    Kanji(KanjiKind),
+    /// [Romaji](https://en.wikipedia.org/wiki/Romaji) and other latin letters, e.g. "a" or "ō"
+    Romaji,
+    /// Latin numbers, e.g. "1"
+    LatinNumeral,
+    /// Zenkaku numbers, e.g. "１"
+    JapaneseNumeral,
+    /// English (hankaku) punctuation, e.g. "."
+    EnglishPunctuation,
+    /// Smart quotes, e.g. "“"
+    SmartQuote,
+    /// Japanese (zenkaku) punctuation and symbols, e.g. "。" or "「"
+    JapanesePunctuation,
+    /// Whitespace, including the ideographic space "　"
+    Space,
+    /// Anything else, e.g. emoji
+    Other,
+}
+
+/// The sub-kind of a Kana char
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KanaKind {
+    /// A full-size Kana without voicing marks, e.g. "か"
+    Plain,
+    /// A small Kana, e.g. "ゃ" or "っ"
+    Small,
+    /// A Kana with a dakuten, e.g. "が"
+    Voiced,
+    /// A Kana with a handakuten, e.g. "ぱ"
+    SemiVoiced,
+}
+
+/// The sub-kind of a Kanji char, by the [CJK
+/// Ideographs](https://en.wikipedia.org/wiki/CJK_Unified_Ideographs) block it's in
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KanjiKind {
+    /// CJK Unified Ideographs, U+4E00 to U+9FFF
+    Common,
+    /// CJK Unified Ideographs Extension A, U+3400 to U+4DBF
+    Rare,
+    /// CJK Unified Ideographs Extension B and later, from U+20000, e.g. "𠮷"
+    Supplementary,
+    /// CJK Compatibility Ideographs, U+F900 to U+FAFF and U+2F800 to U+2FA1F, e.g. "﨑"
+    Compatibility,
+    /// A mark used like Kanji, "〆" or "ヶ" (as in "霞ヶ関"), while the iteration mark "々" is an
+    /// [`IterationMark`](enum.CharClass.html#variant.IterationMark)
+    Mark,
+}
+
+/// The script a [`CharClass`](enum.CharClass.html) belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Script {
+    /// [Hiragana](https://en.wikipedia.org/wiki/Hiragana)
+    Hiragana,
+    /// [Katakana](https://en.wikipedia.org/wiki/Katakana), full or half-width
+    Katakana,
+    /// [Kanji](https://en.wikipedia.org/wiki/Kanji)
+    Kanji,
+    /// [Romaji](https://en.wikipedia.org/wiki/Romaji)
+    Romaji,
+    /// Numbers, punctuation, spaces and marks shared between scripts
+    Common,
+    /// Anything else
+    Other,
+}
+
+impl CharClass {
+    /// The script of this class of chars
+    ///
+    /// ```rust
+    /// # use wanakana::{classify, Script};
+    /// assert_eq!(classify('ゃ').script(), Script::Hiragana);
+    /// assert_eq!(classify('ｶ').script(), Script::Katakana);
+    /// assert_eq!(classify('々').script(), Script::Kanji);
+    /// assert_eq!(classify('ー').script(), Script::Common);
+    /// ```
+    pub fn script(self) -> Script {
+        match self {
+            CharClass::Hiragana(_) => Script::Hiragana,
+            CharClass::Katakana(_) | CharClass::HalfwidthKatakana(_) => Script::Katakana,
+            CharClass::IterationMark(script) => script,
+            CharClass::Kanji(_) => Script::Kanji,
+            CharClass::Romaji => Script::Romaji,
+            CharClass::VoicingMark |
+            CharClass::ProlongedSoundMark |
+            CharClass::LatinNumeral |
+            CharClass::JapaneseNumeral |
+            CharClass::EnglishPunctuation |
+            CharClass::SmartQuote |
+            CharClass::JapanesePunctuation |
+            CharClass::Space => Script::Common,
+            CharClass::Other => Script::Other,
+        }
+    }
+}
+
+/// Classify a single char, e.g. to branch on the kind of char in one call instead of testing it
+/// with several predicates
+///
+/// ```rust
+/// # use wanakana::{classify, CharClass, KanaKind, KanjiKind, Script};
+/// assert_eq!(classify('あ'), CharClass::Hiragana(KanaKind::Plain));
+/// assert_eq!(classify('ッ'), CharClass::Katakana(KanaKind::Small));
+/// assert_eq!(classify('ガ'), CharClass::Katakana(KanaKind::Voiced));
+/// assert_eq!(classify('ﾊ'), CharClass::HalfwidthKatakana(KanaKind::Plain));
+/// assert_eq!(classify('ﾟ'), CharClass::VoicingMark);
+/// assert_eq!(classify('ー'), CharClass::ProlongedSoundMark);
+/// assert_eq!(classify('ゞ'), CharClass::IterationMark(Script::Hiragana));
+/// assert_eq!(classify('漢'), CharClass::Kanji(KanjiKind::Common));
+/// assert_eq!(classify('𠮷'), CharClass::Kanji(KanjiKind::Supplementary));
+/// assert_eq!(classify('〆'), CharClass::Kanji(KanjiKind::Mark));
+/// assert_eq!(classify('ヶ'), CharClass::Kanji(KanjiKind::Mark));
+/// assert_eq!(classify('々'), CharClass::IterationMark(Script::Kanji));
+/// assert_eq!(classify('ō'), CharClass::Romaji);
+/// assert_eq!(classify('２'), CharClass::JapaneseNumeral);
+/// assert_eq!(classify('“'), CharClass::SmartQuote);
+/// assert_eq!(classify('。'), CharClass::JapanesePunctuation);
+/// assert_eq!(classify('　'), CharClass::Space);
+/// assert_eq!(classify('🦀'), CharClass::Other);
+/// ```
+pub fn classify(c: char) -> CharClass {
+    let code = c as u32;
+
+    if c.is_whitespace() {
+        CharClass::Space
+    } else if char_ext::is_in_range(c, &constants::ZENKAKU_NUMBERS) {
+        CharClass::JapaneseNumeral
+    } else if char_ext::is_in_range(c, &constants::LATIN_NUMBERS) {
+        CharClass::LatinNumeral
+    } else if char_ext::is_in_ranges(c, constants::SMART_QUOTE_RANGES) {
+        CharClass::SmartQuote
+    } else if char_ext::is_in_ranges(c, constants::EN_PUNCTUATION_RANGES) {
+        CharClass::EnglishPunctuation
+    } else if code == constants::PROLONGED_SOUND_MARK ||
+        code == constants::HANKAKU_PROLONGED_SOUND_MARK
+    {
+        CharClass::ProlongedSoundMark
+    } else if let Some(script) = iteration_mark_script(code) {
+        CharClass::IterationMark(script)
+    } else if matches!(code, 0x3099..=0x309C | 0xFF9E..=0xFF9F) {
+        CharClass::VoicingMark
+    } else if char_ext::is_in_ranges(c, constants::KANJI_MARK_RANGES) {
+        CharClass::Kanji(KanjiKind::Mark)
+    } else if char_ext::is_in_range(c, &constants::HIRAGANA_CHARS) {
+        match code {
+            constants::HIRAGANA_START..=constants::HIRAGANA_END => {
+                CharClass::Hiragana(kana_kind(code))
+            }
+            // ゟ
+            0x309F => CharClass::Hiragana(KanaKind::Plain),
+            _ => CharClass::Other,
+        }
+    } else if char_ext::is_in_range(c, &constants::KATAKANA_CHARS) {
+        let offset = constants::KATAKANA_START - constants::HIRAGANA_START;
+
+        match code {
+            // ヷヸヹヺ
+            0x30F7..=0x30FA => CharClass::Katakana(KanaKind::Voiced),
+            // ゠ and ・
+            0x30A0 | constants::KANA_SLASH_DOT => CharClass::JapanesePunctuation,
+            // ヿ
+            0x30FF => CharClass::Katakana(KanaKind::Plain),
+            _ => CharClass::Katakana(kana_kind(code - offset)),
+        }
+    } else if char_ext::is_in_range(c, &constants::HANKAKU_KATAKANA) {
+        match code {
+            // ｧ to ｯ
+            0xFF67..=0xFF6F => CharClass::HalfwidthKatakana(KanaKind::Small),
+            _ => CharClass::HalfwidthKatakana(KanaKind::Plain),
+        }
+    } else if char_ext::is_in_ranges(c, constants::JA_PUNCTUATION_RANGES) {
+        CharClass::JapanesePunctuation
+    } else if char_ext::is_in_range(c, &constants::COMMON_CJK) {
+        CharClass::Kanji(KanjiKind::Common)
+    } else if char_ext::is_in_range(c, &constants::RARE_CJK) {
+        CharClass::Kanji(KanjiKind::Rare)
+    } else if char_ext::is_in_ranges(c, constants::SUPPLEMENTARY_CJK_RANGES) {
+        CharClass::Kanji(KanjiKind::Supplementary)
+    } else if char_ext::is_in_ranges(c, constants::COMPATIBILITY_CJK_RANGES) {
+        CharClass::Kanji(KanjiKind::Compatibility)
+    } else if c.is_alphabetic() && c.is_romaji() {
+        CharClass::Romaji
+    } else {
+        CharClass::Other
+    }
+}
+
+/// The script of the char an iteration mark repeats, if `code` is an iteration mark
+fn iteration_mark_script(code: u32) -> Option<Script> {
+    match code {
+        // ゝゞ
+        0x309D..=0x309E => Some(Script::Hiragana),
+        // ヽヾ
+        0x30FD..=0x30FE => Some(Script::Katakana),
+        // 々〻
+        0x3005 | 0x303B => Some(Script::Kanji),
+        // 〱〲〳〴〵, repeating either Hiragana or Katakana
+        0x3031..=0x3035 => Some(Script::Common),
+        _ => None,
+    }
+}
+
+/// The kind of the Hiragana `code` (or the Hiragana equivalent of a Katakana)
+// `u32::is_multiple_of` would need Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+fn kana_kind(code: u32) -> KanaKind {
+    match code {
+        // ぁぃぅぇぉ
+        0x3041 | 0x3043 | 0x3045 | 0x3047 | 0x3049 => KanaKind::Small,
+        // っゃゅょゎゕゖ
+        0x3063 | 0x3083 | 0x3085 | 0x3087 | 0x308E | 0x3095 | 0x3096 => KanaKind::Small,
+        // が to ぢ
+        0x304C..=0x3062 if code % 2 == 0 => KanaKind::Voiced,
+        // づでど
+        0x3065 | 0x3067 | 0x3069 => KanaKind::Voiced,
+        // は to ぽ, where each kana is followed by its voiced and semi-voiced forms
+        0x306F..=0x307D => match (code - 0x306F) % 3 {
+            1 => KanaKind::Voiced,
+            2 => KanaKind::SemiVoiced,
+            _ => KanaKind::Plain,
+        },
+        // ゔ
+        0x3094 => KanaKind::Voiced,
+        _ => KanaKind::Plain,
+    }
+}
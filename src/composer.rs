@@ -0,0 +1,215 @@
+use char_ext::is_consonant;
+use constants;
+use options::ConversionOptions;
+use std::cmp;
+use output;
+use to_kana::{next_kana, romaji_to_kana, write_kana_chunk};
+
+/// Incremental romaji to kana composer, for converting input one keystroke at a time like an
+/// [IME](https://en.wikipedia.org/wiki/Input_method)
+///
+/// Romaji is held in a pending buffer until the following keystrokes can no longer change how it
+/// is converted, at which point it's committed as kana. The same mapping tables (and options) as
+/// [`to_kana`](fn.to_kana.html) are used, so composing a string key by key always produces the
+/// same kana as converting it all at once.
+///
+/// ```rust
+/// # use wanakana::Composer;
+/// let mut composer = Composer::new();
+///
+/// assert_eq!(composer.push('k'), ("", "k"));
+/// assert_eq!(composer.push('a'), ("か", ""));
+/// assert_eq!(composer.push('n'), ("か", "n"));
+/// assert_eq!(composer.push('j'), ("か", "nj"));
+/// assert_eq!(composer.push('i'), ("かんじ", ""));
+///
+/// composer.push_str("tt");
+/// assert_eq!((composer.committed(), composer.pending()), ("かんじっ", "t"));
+/// assert!(composer.backspace());
+/// assert_eq!((composer.committed(), composer.pending()), ("かんじっ", ""));
+///
+/// composer.push_str("KON");
+/// assert_eq!(composer.finish(), "かんじっコン");
+/// ```
+///
+/// With the same options, including `ime_mode`, the result matches
+/// [`to_kana_with_options`](fn.to_kana_with_options.html):
+///
+/// ```rust
+/// # use wanakana::{to_kana_with_options, Composer, ConversionOptions};
+/// let ime = ConversionOptions {
+///     ime_mode: true,
+///     ..ConversionOptions::default()
+/// };
+///
+/// for input in &["kanい", "kanyo", "kany", "kan"] {
+///     let mut composer = Composer::with_options(ime.clone());
+///     composer.push_str(input);
+///     assert_eq!(composer.finish(), to_kana_with_options(input, &ime));
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Composer {
+    options: ConversionOptions,
+    committed: String,
+    pending: String,
+    katakana: bool,
+    // The chars of `pending`, reused between keystrokes to avoid allocating
+    chars: Vec<char>,
+}
+
+impl Composer {
+    /// Create an empty composer, using the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty composer, using the given options
+    pub fn with_options(options: ConversionOptions) -> Self {
+        Composer {
+            options,
+            ..Composer::default()
+        }
+    }
+
+    /// Create an empty composer that produces katakana for every chunk (not just uppercase ones)
+    pub(crate) fn katakana(options: ConversionOptions) -> Self {
+        Composer {
+            katakana: true,
+            ..Composer::with_options(options)
+        }
+    }
+
+    /// The kana that has been committed so far
+    pub fn committed(&self) -> &str {
+        &self.committed
+    }
+
+    /// The romaji that hasn't been converted yet
+    pub fn pending(&self) -> &str {
+        &self.pending
+    }
+
+    /// Add a keystroke, returning the committed kana and the pending romaji
+    pub fn push(&mut self, c: char) -> (&str, &str) {
+        self.pending.push(c);
+        self.commit_decided();
+        (&self.committed, &self.pending)
+    }
+
+    /// Add each char of `input` as a keystroke, returning the committed kana and the pending
+    /// romaji
+    pub fn push_str(&mut self, input: &str) -> (&str, &str) {
+        for c in input.chars() {
+            self.pending.push(c);
+            self.commit_decided();
+        }
+
+        (&self.committed, &self.pending)
+    }
+
+    /// Remove the last pending char or, if nothing is pending, the last committed char. Returns
+    /// `false` if the composer was already empty.
+    pub fn backspace(&mut self) -> bool {
+        self.pending.pop().or_else(|| self.committed.pop()).is_some()
+    }
+
+    /// Commit any pending romaji, returning all of the composed kana and leaving the composer
+    /// empty
+    ///
+    /// A trailing "n" is converted to ん, except with `ime_mode`, where it's kept as romaji like
+    /// [`to_kana_with_options`](fn.to_kana_with_options.html) keeps it:
+    ///
+    /// ```rust
+    /// # use wanakana::{Composer, ConversionOptions};
+    /// let mut composer = Composer::new();
+    /// composer.push_str("kan");
+    /// assert_eq!(composer.finish(), "かん");
+    ///
+    /// let mut composer = Composer::with_options(ConversionOptions {
+    ///     ime_mode: true,
+    ///     ..ConversionOptions::default()
+    /// });
+    /// composer.push_str("kan");
+    /// assert_eq!(composer.finish(), "かn");
+    /// ```
+    pub fn finish(&mut self) -> String {
+        let pending = romaji_to_kana(&self.pending, &self.options, self.katakana);
+        self.pending.clear();
+        self.committed.push_str(&pending);
+        ::std::mem::take(&mut self.committed)
+    }
+
+    /// Move the kana committed so far to `out`, leaving any pending romaji
+    pub(crate) fn drain_committed(&mut self, out: &mut String) {
+        out.push_str(&self.committed);
+        self.committed.clear();
+    }
+
+    /// Discard all committed and pending input
+    pub fn clear(&mut self) {
+        self.committed.clear();
+        self.pending.clear();
+    }
+
+    /// Commit chunks from the start of the pending buffer for as long as more input can't change
+    /// how they're converted
+    fn commit_decided(&mut self) {
+        let max_chunk_size = cmp::max(3, self.options.custom_kana_mapping.max_key_len());
+
+        if self.pending.is_empty() || self.is_undecided_from(0) {
+            return;
+        }
+
+        self.chars.clear();
+        self.chars.extend(self.pending.chars());
+        let mut cursor = 0;
+        let mut consumed = 0;
+
+        loop {
+            let chars = &self.chars[cursor..];
+            let (chunk_size, kana_chunk) = next_kana(chars, &self.options, max_chunk_size);
+            let chunk = &chars[..chunk_size];
+            let (options, katakana) = (&self.options, self.katakana);
+            output::push_to(&mut self.committed, |committed| {
+                write_kana_chunk(committed, chunk, kana_chunk, options, katakana)
+            });
+
+            cursor += chunk_size;
+            consumed += chunk.iter().map(|c| c.len_utf8()).sum::<usize>();
+
+            if cursor == self.chars.len() || self.is_undecided_from(consumed) {
+                break;
+            }
+        }
+
+        self.pending.drain(..consumed);
+        self.chars.clear();
+    }
+
+    /// Test if the pending romaji after its first `start` bytes (which are about to be committed)
+    /// could still be converted differently depending on what's typed next, in which case it has
+    /// to be held
+    fn is_undecided_from(&self, start: usize) -> bool {
+        let pending = self.pending[start..].chars().map(|c| c.to_ascii_lowercase());
+        let is_proper_prefix = |key: &str| {
+            let mut key = key.chars();
+            pending.clone().all(|c| key.next() == Some(c)) && key.next().is_some()
+        };
+
+        let mut chars = pending.clone();
+        let special_case = match (chars.next(), chars.next(), chars.next()) {
+            // "tc" may become "tch" ("っch")
+            (Some('t'), Some('c'), None) => true,
+            // "nk" may become "nka" ("んか"), and "nn" may become "nna" ("んな")
+            (Some('n'), Some(c), None) => is_consonant(c, false),
+            _ => false,
+        };
+
+        special_case || constants::FROM_ROMAJI_TRIE.has_longer_key(pending.clone()) ||
+            self.options
+                .custom_kana_mapping
+                .inserted_keys()
+                .any(is_proper_prefix)
+    }
+}
@@ -0,0 +1,157 @@
+use hiragana_to_katakana::{hiragana_to_katakana_cow_with_options,
+                           hiragana_to_katakana_into_with_options,
+                           hiragana_to_katakana_with_options};
+use katakana_to_hiragana::{katakana_to_hiragana_cow_with_options,
+                           katakana_to_hiragana_into_with_options,
+                           katakana_to_hiragana_with_options};
+use options::ConversionOptions;
+use std::borrow::Cow;
+use std::fmt;
+use to_hiragana::{to_hiragana_cow_with_options, to_hiragana_into_with_options,
+                  to_hiragana_with_options};
+use to_kana::{to_kana_cow_with_options, to_kana_into_with_options, to_kana_with_options};
+use to_katakana::{to_katakana_cow_with_options, to_katakana_into_with_options,
+                  to_katakana_with_options};
+use to_romaji::{to_romaji_cow_with_options, to_romaji_into_with_options,
+                to_romaji_with_options};
+
+/// A reusable converter, for applying the same options (and custom mappings) to many inputs
+///
+/// ```rust
+/// # use wanakana::{ConversionOptions, Converter};
+/// let converter = Converter::new(ConversionOptions {
+///     custom_kana_mapping: vec![("wi", "ゐ"), ("we", "ゑ")].into_iter().collect(),
+///     custom_romaji_mapping: vec![("ゐ", "wi"), ("ゑ", "we")].into_iter().collect(),
+///     ..ConversionOptions::default()
+/// });
+///
+/// assert_eq!(converter.to_kana("wiwe"), "ゐゑ");
+/// assert_eq!(converter.to_katakana("wiwe"), "ヰヱ");
+/// assert_eq!(converter.to_romaji("ゐゑ"), "wiwe");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Converter {
+    options: ConversionOptions,
+}
+
+impl Converter {
+    /// Create a converter using the given options
+    pub fn new(options: ConversionOptions) -> Self {
+        Converter { options }
+    }
+
+    /// The options used by this converter
+    pub fn options(&self) -> &ConversionOptions {
+        &self.options
+    }
+
+    /// Convert Romaji to Kana, see [`to_kana`](fn.to_kana.html)
+    pub fn to_kana(&self, input: &str) -> String {
+        to_kana_with_options(input, &self.options)
+    }
+
+    /// Convert input to Hiragana, see [`to_hiragana`](fn.to_hiragana.html)
+    pub fn to_hiragana(&self, input: &str) -> String {
+        to_hiragana_with_options(input, &self.options)
+    }
+
+    /// Convert input to Katakana, see [`to_katakana`](fn.to_katakana.html)
+    pub fn to_katakana(&self, input: &str) -> String {
+        to_katakana_with_options(input, &self.options)
+    }
+
+    /// Convert Kana to Romaji, see [`to_romaji`](fn.to_romaji.html)
+    pub fn to_romaji(&self, input: &str) -> String {
+        to_romaji_with_options(input, &self.options)
+    }
+
+    /// Convert Hiragana to Katakana, see [`hiragana_to_katakana`](fn.hiragana_to_katakana.html)
+    pub fn hiragana_to_katakana(&self, input: &str) -> String {
+        hiragana_to_katakana_with_options(input, &self.options)
+    }
+
+    /// Convert Katakana to Hiragana, see [`katakana_to_hiragana`](fn.katakana_to_hiragana.html)
+    pub fn katakana_to_hiragana(&self, input: &str) -> String {
+        katakana_to_hiragana_with_options(input, &self.options)
+    }
+
+    /// Convert Romaji to Kana, borrowing `input` if it has no Romaji
+    ///
+    /// ```rust
+    /// # use std::borrow::Cow;
+    /// # use wanakana::{ConversionOptions, Converter};
+    /// let converter = Converter::new(ConversionOptions::default());
+    /// assert_eq!(converter.to_kana_cow("kana"), "かな");
+    /// assert!(matches!(converter.to_kana_cow("かな"), Cow::Borrowed("かな")));
+    /// ```
+    pub fn to_kana_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        to_kana_cow_with_options(input, &self.options)
+    }
+
+    /// Convert input to Hiragana, borrowing `input` if there is nothing to convert
+    pub fn to_hiragana_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        to_hiragana_cow_with_options(input, &self.options)
+    }
+
+    /// Convert input to Katakana, borrowing `input` if there is nothing to convert
+    pub fn to_katakana_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        to_katakana_cow_with_options(input, &self.options)
+    }
+
+    /// Convert Kana to Romaji, borrowing `input` if it has no Kana
+    pub fn to_romaji_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        to_romaji_cow_with_options(input, &self.options)
+    }
+
+    /// Convert Hiragana to Katakana, borrowing `input` if it has no Hiragana
+    pub fn hiragana_to_katakana_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        hiragana_to_katakana_cow_with_options(input, &self.options)
+    }
+
+    /// Convert Katakana to Hiragana, borrowing `input` if it has no Katakana
+    pub fn katakana_to_hiragana_cow<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        katakana_to_hiragana_cow_with_options(input, &self.options)
+    }
+
+    /// Convert Romaji to Kana, writing the result to `out`
+    pub fn to_kana_into<W: fmt::Write>(&self, input: &str, out: &mut W) -> fmt::Result {
+        to_kana_into_with_options(input, &self.options, out)
+    }
+
+    /// Convert input to Hiragana, writing the result to `out`
+    pub fn to_hiragana_into<W: fmt::Write>(&self, input: &str, out: &mut W) -> fmt::Result {
+        to_hiragana_into_with_options(input, &self.options, out)
+    }
+
+    /// Convert input to Katakana, writing the result to `out`
+    pub fn to_katakana_into<W: fmt::Write>(&self, input: &str, out: &mut W) -> fmt::Result {
+        to_katakana_into_with_options(input, &self.options, out)
+    }
+
+    /// Convert Kana to Romaji, writing the result to `out`
+    pub fn to_romaji_into<W: fmt::Write>(&self, input: &str, out: &mut W) -> fmt::Result {
+        to_romaji_into_with_options(input, &self.options, out)
+    }
+
+    /// Convert Hiragana to Katakana, writing the result to `out`
+    pub fn hiragana_to_katakana_into<W>(&self, input: &str, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        hiragana_to_katakana_into_with_options(input, &self.options, out)
+    }
+
+    /// Convert Katakana to Hiragana, writing the result to `out`
+    pub fn katakana_to_hiragana_into<W>(&self, input: &str, out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        katakana_to_hiragana_into_with_options(input, &self.options, out)
+    }
+}
+
+impl From<ConversionOptions> for Converter {
+    fn from(options: ConversionOptions) -> Self {
+        Converter::new(options)
+    }
+}
@@ -0,0 +1,161 @@
+use char_ext;
+use constants;
+use output;
+use std::borrow::Cow;
+use std::fmt;
+use std::str;
+
+/// Convert half-width (hankaku) [Katakana](https://en.wikipedia.org/wiki/Katakana) to full-width
+/// (zenkaku) Katakana, combining the separate voiced sound marks ﾞ and ﾟ with the preceding kana
+///
+/// ```rust
+/// # use wanakana::halfwidth_to_katakana;
+/// assert_eq!(halfwidth_to_katakana("ｶﾀｶﾅ"), "カタカナ");
+/// assert_eq!(halfwidth_to_katakana("ｹﾞｰﾑ ﾊﾟｰﾃｨｰ"), "ゲーム パーティー");
+/// assert_eq!(halfwidth_to_katakana("｢ｳﾞｧｲｵﾘﾝ｣､ﾋﾟｱﾉ｡"), "「ヴァイオリン」、ピアノ。");
+/// assert_eq!(halfwidth_to_katakana("ひらがな and カタカナ"), "ひらがな and カタカナ");
+/// ```
+pub fn halfwidth_to_katakana(input: &str) -> String {
+    output::to_string(input.len(), |kata| halfwidth_to_katakana_into(input, kata))
+}
+
+/// Half-width to full-width Katakana conversion one char at a time, holding back a half-width
+/// kana until it's known whether a voiced sound mark follows it
+#[derive(Clone, Debug, Default)]
+pub(crate) struct HalfwidthToKatakana {
+    pending: Option<char>,
+}
+
+impl HalfwidthToKatakana {
+    /// Convert the next char, writing any finished full-width chars to `kata`
+    pub(crate) fn push<W: fmt::Write>(&mut self, c: char, kata: &mut W) -> fmt::Result {
+        if let Some(kana) = self.pending.take() {
+            // Try the kana combined with a following voiced sound mark first
+            let mut buffer = [0; 8];
+            let len = kana.encode_utf8(&mut buffer).len();
+            let len = len + c.encode_utf8(&mut buffer[len..]).len();
+            let combined = str::from_utf8(&buffer[..len])
+                .ok()
+                .and_then(|chunk| constants::HANKAKU_TO_ZENKAKU_KATAKANA.get::<str>(chunk));
+
+            if let Some(zenkaku) = combined {
+                return kata.write_str(zenkaku);
+            }
+
+            write_zenkaku(kana, kata)?;
+        }
+
+        if is_halfwidth_kana(c) {
+            self.pending = Some(c);
+            Ok(())
+        } else {
+            kata.write_char(c)
+        }
+    }
+
+    /// Write the held back kana (if any) to `kata`
+    pub(crate) fn finish<W: fmt::Write>(&mut self, kata: &mut W) -> fmt::Result {
+        match self.pending.take() {
+            Some(kana) => write_zenkaku(kana, kata),
+            None => Ok(()),
+        }
+    }
+}
+
+fn write_zenkaku<W: fmt::Write>(c: char, kata: &mut W) -> fmt::Result {
+    let mut buffer = [0; 4];
+
+    match constants::HANKAKU_TO_ZENKAKU_KATAKANA.get::<str>(c.encode_utf8(&mut buffer)) {
+        Some(zenkaku) => kata.write_str(zenkaku),
+        None => kata.write_char(c),
+    }
+}
+
+/// Convert full-width (zenkaku) [Katakana](https://en.wikipedia.org/wiki/Katakana) to half-width
+/// (hankaku) Katakana, splitting voiced kana into the kana and a separate ﾞ or ﾟ mark
+///
+/// Katakana without a half-width form (like ヵ and ヶ) and
+/// [Hiragana](https://en.wikipedia.org/wiki/Hiragana) are passed through, so Hiragana should be
+/// converted with [`hiragana_to_katakana`](fn.hiragana_to_katakana.html) first if needed.
+///
+/// ```rust
+/// # use wanakana::katakana_to_halfwidth;
+/// assert_eq!(katakana_to_halfwidth("カタカナ"), "ｶﾀｶﾅ");
+/// assert_eq!(katakana_to_halfwidth("ゲーム パーティー"), "ｹﾞｰﾑ ﾊﾟｰﾃｨｰ");
+/// assert_eq!(katakana_to_halfwidth("「ヴァイオリン」、ピアノ。"), "｢ｳﾞｧｲｵﾘﾝ｣､ﾋﾟｱﾉ｡");
+/// assert_eq!(katakana_to_halfwidth("ひらがなとヶ"), "ひらがなとヶ");
+/// ```
+pub fn katakana_to_halfwidth(input: &str) -> String {
+    output::to_string(input.len(), |hankaku| katakana_to_halfwidth_into(input, hankaku))
+}
+
+/// Convert half-width Katakana to full-width, borrowing `input` if it has no half-width Katakana
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use wanakana::halfwidth_to_katakana_cow;
+/// assert_eq!(halfwidth_to_katakana_cow("ｶﾀｶﾅ"), "カタカナ");
+/// assert!(matches!(halfwidth_to_katakana_cow("カタカナ"), Cow::Borrowed("カタカナ")));
+/// ```
+pub fn halfwidth_to_katakana_cow(input: &str) -> Cow<'_, str> {
+    normalize_halfwidth(input)
+}
+
+/// Convert half-width Katakana to full-width, writing the result to `out`
+pub fn halfwidth_to_katakana_into<W: fmt::Write>(input: &str, out: &mut W) -> fmt::Result {
+    let mut state = HalfwidthToKatakana::default();
+
+    for c in input.chars() {
+        state.push(c, out)?;
+    }
+
+    state.finish(out)
+}
+
+/// Convert full-width Katakana to half-width, borrowing `input` if it has nothing to convert
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use wanakana::katakana_to_halfwidth_cow;
+/// assert_eq!(katakana_to_halfwidth_cow("カタカナ"), "ｶﾀｶﾅ");
+/// assert!(matches!(katakana_to_halfwidth_cow("ｶﾀｶﾅ"), Cow::Borrowed("ｶﾀｶﾅ")));
+/// ```
+pub fn katakana_to_halfwidth_cow(input: &str) -> Cow<'_, str> {
+    if input.chars().any(|c| zenkaku_to_hankaku(c).is_some()) {
+        Cow::Owned(katakana_to_halfwidth(input))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Convert full-width Katakana to half-width, writing the result to `out`
+pub fn katakana_to_halfwidth_into<W: fmt::Write>(input: &str, out: &mut W) -> fmt::Result {
+    for c in input.chars() {
+        match zenkaku_to_hankaku(c) {
+            Some(kana) => out.write_str(kana)?,
+            None => out.write_char(c)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert any half-width Katakana in `input` to full-width, borrowing `input` if there is none
+pub(crate) fn normalize_halfwidth(input: &str) -> Cow<'_, str> {
+    if input.chars().any(is_halfwidth_kana) {
+        Cow::Owned(halfwidth_to_katakana(input))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+fn zenkaku_to_hankaku(c: char) -> Option<&'static str> {
+    let mut buffer = [0; 4];
+    constants::ZENKAKU_TO_HANKAKU_KATAKANA
+        .get::<str>(c.encode_utf8(&mut buffer))
+        .cloned()
+}
+
+pub(crate) fn is_halfwidth_kana(c: char) -> bool {
+    char_ext::is_in_ranges(c, &[constants::KANA_PUNCTUATION, constants::HANKAKU_KATAKANA])
+}
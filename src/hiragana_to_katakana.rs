@@ -1,5 +1,9 @@
 use char_ext::CharExt;
 use constants;
+use options::ConversionOptions;
+use output;
+use std::borrow::Cow;
+use std::fmt::{self, Write};
 
 /// Convert [Hiragana](https://en.wikipedia.org/wiki/Hiragana) to
 /// [Katakana](https://en.wikipedia.org/wiki/Katakana)
@@ -13,26 +17,82 @@ use constants;
 /// );
 /// ```
 pub fn hiragana_to_katakana(input: &str) -> String {
-    // TODO: This is mostly a 1-to-1 port, can probably be improved
-    let mut kata = String::new();
-
-    for c in input.chars() {
-        if (c as u32) == constants::PROLONGED_SOUND_MARK ||
-            (c as u32) == constants::KANA_SLASH_DOT
-        {
-            kata.push(c);
-        } else if c.is_hiragana() {
-            // Shift charcode.
-            let code = c as u32 + constants::KATAKANA_START - constants::HIRAGANA_START;
-
-            if let Some(kata_char) = ::std::char::from_u32(code) {
-                kata.push(kata_char);
-            }
-        } else {
-            // Pass non-hiragana chars through
-            kata.push(c)
+    hiragana_to_katakana_with_options(input, &ConversionOptions::default())
+}
+
+/// Convert [Hiragana](https://en.wikipedia.org/wiki/Hiragana) to
+/// [Katakana](https://en.wikipedia.org/wiki/Katakana), using the given options
+///
+/// None of the options currently change the output of this conversion. They are accepted so that
+/// the same options can be passed to every converter.
+pub fn hiragana_to_katakana_with_options(input: &str, options: &ConversionOptions) -> String {
+    output::to_string(input.len(), |kata| {
+        hiragana_to_katakana_into_with_options(input, options, kata)
+    })
+}
+
+pub(crate) fn hiragana_to_katakana_into_with_options<W: fmt::Write>(
+    input: &str,
+    _options: &ConversionOptions,
+    out: &mut W,
+) -> fmt::Result {
+    KatakanaWriter(out).write_str(input)
+}
+
+/// A writer that converts the Hiragana written to it to Katakana, before writing it to the inner
+/// writer
+pub(crate) struct KatakanaWriter<'a, W: 'a>(pub(crate) &'a mut W);
+
+impl<'a, W: fmt::Write> fmt::Write for KatakanaWriter<'a, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.0.write_char(hiragana_to_katakana_char(c))?;
         }
+
+        Ok(())
+    }
+}
+
+/// Convert a single Hiragana char to Katakana, passing other chars through
+pub(crate) fn hiragana_to_katakana_char(c: char) -> char {
+    if (c as u32) == constants::PROLONGED_SOUND_MARK || (c as u32) == constants::KANA_SLASH_DOT {
+        c
+    } else if c.is_hiragana() {
+        // Shift charcode.
+        let code = c as u32 + constants::KATAKANA_START - constants::HIRAGANA_START;
+        ::std::char::from_u32(code).unwrap_or(c)
+    } else {
+        // Pass non-hiragana chars through
+        c
     }
+}
+
+/// Convert [Hiragana](https://en.wikipedia.org/wiki/Hiragana) to
+/// [Katakana](https://en.wikipedia.org/wiki/Katakana), borrowing `input` if it has no Hiragana
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use wanakana::hiragana_to_katakana_cow;
+/// assert_eq!(hiragana_to_katakana_cow("ひらがな"), "ヒラガナ");
+/// assert!(matches!(hiragana_to_katakana_cow("no kana"), Cow::Borrowed("no kana")));
+/// ```
+pub fn hiragana_to_katakana_cow(input: &str) -> Cow<'_, str> {
+    hiragana_to_katakana_cow_with_options(input, &ConversionOptions::default())
+}
+
+/// Convert [Hiragana](https://en.wikipedia.org/wiki/Hiragana) to
+/// [Katakana](https://en.wikipedia.org/wiki/Katakana), writing the result to `out`
+pub fn hiragana_to_katakana_into<W: fmt::Write>(input: &str, out: &mut W) -> fmt::Result {
+    hiragana_to_katakana_into_with_options(input, &ConversionOptions::default(), out)
+}
 
-    kata
+pub(crate) fn hiragana_to_katakana_cow_with_options<'a>(
+    input: &'a str,
+    options: &ConversionOptions,
+) -> Cow<'a, str> {
+    if input.chars().any(|c| c.is_hiragana()) {
+        Cow::Owned(hiragana_to_katakana_with_options(input, options))
+    } else {
+        Cow::Borrowed(input)
+    }
 }
@@ -0,0 +1,86 @@
+use constants;
+use std::char;
+
+/// Convert [Hiragana](https://en.wikipedia.org/wiki/Hiragana) to
+/// [Katakana](https://en.wikipedia.org/wiki/Katakana) in place, without allocating
+///
+/// Hiragana (ぁ to ゖ) and the Katakana they map to (ァ to ヶ) are all 3 bytes long in UTF-8, so
+/// the conversion never changes the length of `input`.
+///
+/// ```rust
+/// # use wanakana::make_katakana_in_place;
+/// let mut input = String::from("ひらがな is a type of かな、げーむ");
+/// make_katakana_in_place(&mut input);
+/// assert_eq!(input, "ヒラガナ is a type of カナ、ゲーム");
+/// ```
+pub fn make_katakana_in_place(input: &mut str) {
+    let offset = constants::KATAKANA_START - constants::HIRAGANA_START;
+    shift_in_place(input, constants::HIRAGANA_START, constants::HIRAGANA_END, offset as i32);
+}
+
+/// Convert [Katakana](https://en.wikipedia.org/wiki/Katakana) to
+/// [Hiragana](https://en.wikipedia.org/wiki/Hiragana) in place, without allocating
+///
+/// Unlike [`katakana_to_hiragana`](fn.katakana_to_hiragana.html), long vowels are kept as ー
+/// (expanding them would change the length of `input`), and half-width Katakana isn't converted.
+///
+/// ```rust
+/// # use wanakana::make_hiragana_in_place;
+/// let mut input = String::from("カタカナ is a type of カナ、ゲーム ｶﾅ");
+/// make_hiragana_in_place(&mut input);
+/// assert_eq!(input, "かたかな is a type of かな、げーむ ｶﾅ");
+/// ```
+pub fn make_hiragana_in_place(input: &mut str) {
+    let offset = constants::KATAKANA_START - constants::HIRAGANA_START;
+    let katakana_end = constants::HIRAGANA_END + offset;
+    shift_in_place(input, constants::KATAKANA_START, katakana_end, -(offset as i32));
+}
+
+/// Shift every char of `input` between `start` and `end` (inclusive) by `offset`, where the
+/// shifted chars must have the same UTF-8 length as the originals
+fn shift_in_place(input: &mut str, start: u32, end: u32, offset: i32) {
+    // SAFETY: Each char replaced below is re-encoded as a char of the same length at the same
+    // position, so the bytes remain valid UTF-8.
+    let bytes = unsafe { input.as_bytes_mut() };
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let len = utf8_len(bytes[index]);
+        let code = decode(&bytes[index..index + len]);
+
+        if (start..=end).contains(&code) {
+            let shifted = char::from_u32((code as i32 + offset) as u32)
+                .filter(|shifted| shifted.len_utf8() == len);
+
+            if let Some(shifted) = shifted {
+                shifted.encode_utf8(&mut bytes[index..index + len]);
+            }
+        }
+
+        index += len;
+    }
+}
+
+/// The length of the UTF-8 sequence starting with the byte `lead`
+fn utf8_len(lead: u8) -> usize {
+    match lead {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+/// Decode the code point of a single UTF-8 sequence
+fn decode(bytes: &[u8]) -> u32 {
+    let lead = match bytes.len() {
+        1 => return u32::from(bytes[0]),
+        2 => u32::from(bytes[0] & 0x1F),
+        3 => u32::from(bytes[0] & 0x0F),
+        _ => u32::from(bytes[0] & 0x07),
+    };
+
+    bytes[1..]
+        .iter()
+        .fold(lead, |code, &byte| (code << 6) | u32::from(byte & 0x3F))
+}
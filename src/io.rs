@@ -0,0 +1,290 @@
+use options::ConversionOptions;
+use std::io::{self, Read, Write};
+use std::str;
+use stream::{Conversion, Convert};
+
+/// The size of the chunks read from the inner reader of a
+/// [`ConvertReader`](struct.ConvertReader.html)
+const READ_CHUNK_SIZE: usize = 8 * 1024;
+
+/// A reader that converts the UTF-8 text read from another reader, e.g. to convert a file too large
+/// to read into a `String`
+///
+/// UTF-8 sequences and Romaji split across reads from the inner reader are converted the same as
+/// if they had been read at once. Reading fails with `ErrorKind::InvalidData` if the inner reader
+/// returns invalid UTF-8.
+///
+/// ```rust
+/// # use std::io::Read;
+/// # use wanakana::{Conversion, ConvertReader};
+/// let input: &[u8] = "カタカナ to ひらがな".as_bytes();
+/// let mut reader = ConvertReader::new(input, Conversion::Hiragana);
+///
+/// let mut hiragana = String::new();
+/// reader.read_to_string(&mut hiragana).unwrap();
+/// assert_eq!(hiragana, "かたかな と ひらがな");
+/// ```
+pub struct ConvertReader<R> {
+    inner: R,
+    conversion: Box<dyn Convert>,
+    decoder: Utf8Decoder,
+    output: String,
+    position: usize,
+    finished: bool,
+}
+
+impl<R: Read> ConvertReader<R> {
+    /// Create a reader converting the text read from `inner`
+    pub fn new(inner: R, conversion: Conversion) -> Self {
+        ConvertReader::with_options(inner, conversion, &ConversionOptions::default())
+    }
+
+    /// Create a reader converting the text read from `inner`, using the given options
+    pub fn with_options(inner: R, conversion: Conversion, options: &ConversionOptions) -> Self {
+        ConvertReader {
+            inner,
+            conversion: conversion.start(options),
+            decoder: Utf8Decoder::default(),
+            output: String::new(),
+            position: 0,
+            finished: false,
+        }
+    }
+
+    /// Get a reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner reader
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwrap the inner reader, discarding any input that was read but not yet converted
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for ConvertReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            if self.position < self.output.len() {
+                let output = &self.output.as_bytes()[self.position..];
+                let len = output.len().min(buf.len());
+                buf[..len].copy_from_slice(&output[..len]);
+                self.position += len;
+                return Ok(len);
+            }
+
+            if self.finished || buf.is_empty() {
+                return Ok(0);
+            }
+
+            self.output.clear();
+            self.position = 0;
+
+            let mut chunk = [0; READ_CHUNK_SIZE];
+            let read = match self.inner.read(&mut chunk) {
+                Ok(read) => read,
+                Err(ref err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+
+            if read == 0 {
+                self.decoder.finish()?;
+                self.conversion.finish(&mut self.output);
+                self.finished = true;
+            } else {
+                let conversion = &mut self.conversion;
+                let output = &mut self.output;
+                self.decoder
+                    .decode(&chunk[..read], |c| conversion.push(c, output))?;
+            }
+        }
+    }
+}
+
+/// A writer that converts UTF-8 text before writing it to another writer, e.g. to convert a file
+/// too large to read into a `String`
+///
+/// UTF-8 sequences and Romaji split across writes are converted the same as if they had been
+/// written at once. Writing fails with `ErrorKind::InvalidData` if the text isn't valid UTF-8.
+///
+/// Since the end of the input can change how it's converted (e.g. a trailing "n" becomes ん),
+/// [`flush`](#method.flush) only writes the output that can no longer change. Call
+/// [`finish`](#method.finish) after writing all of the input to write the rest. Dropping the
+/// writer also finishes the conversion, but ignores any errors.
+///
+/// ```rust
+/// # use std::io::Write;
+/// # use wanakana::{Conversion, ConvertWriter};
+/// let mut writer = ConvertWriter::new(Vec::new(), Conversion::Katakana);
+/// writer.write_all(b"toukyou").unwrap();
+/// writer.write_all("、 おおさか, kyoun".as_bytes()).unwrap();
+///
+/// let katakana = writer.finish().unwrap();
+/// assert_eq!(String::from_utf8(katakana).unwrap(), "トウキョウ、 オオサカ、 キョウン");
+/// ```
+pub struct ConvertWriter<W: Write> {
+    inner: Option<W>,
+    conversion: Box<dyn Convert>,
+    decoder: Utf8Decoder,
+    output: String,
+    written: usize,
+}
+
+impl<W: Write> ConvertWriter<W> {
+    /// Create a writer converting the text written to `inner`
+    pub fn new(inner: W, conversion: Conversion) -> Self {
+        ConvertWriter::with_options(inner, conversion, &ConversionOptions::default())
+    }
+
+    /// Create a writer converting the text written to `inner`, using the given options
+    pub fn with_options(inner: W, conversion: Conversion, options: &ConversionOptions) -> Self {
+        ConvertWriter {
+            inner: Some(inner),
+            conversion: conversion.start(options),
+            decoder: Utf8Decoder::default(),
+            output: String::new(),
+            written: 0,
+        }
+    }
+
+    /// Get a reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        self.inner.as_ref().expect("inner writer taken")
+    }
+
+    /// Get a mutable reference to the inner writer
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner.as_mut().expect("inner writer taken")
+    }
+
+    /// Convert the rest of the input, write it to the inner writer, and return the inner writer
+    ///
+    /// Fails with `ErrorKind::InvalidData` if the input ended partway through a UTF-8 sequence.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.finish_conversion()?;
+        Ok(self.inner.take().expect("inner writer taken"))
+    }
+
+    fn finish_conversion(&mut self) -> io::Result<()> {
+        self.decoder.finish()?;
+        self.conversion.finish(&mut self.output);
+        self.write_output()?;
+        self.get_mut().flush()
+    }
+
+    /// Write all of the converted output to the inner writer
+    fn write_output(&mut self) -> io::Result<()> {
+        let inner = self.inner.as_mut().expect("inner writer taken");
+
+        // The output is written as bytes, so a partial write may end partway through a char
+        while self.written < self.output.len() {
+            match inner.write(&self.output.as_bytes()[self.written..]) {
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write the converted output",
+                    ))
+                }
+                Ok(written) => self.written += written,
+                Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        self.output.clear();
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for ConvertWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Output left over from a failed write has to be written first, so that `buf` is only
+        // reported as written once it's been converted
+        self.write_output()?;
+
+        let conversion = &mut self.conversion;
+        let output = &mut self.output;
+        self.decoder.decode(buf, |c| conversion.push(c, output))?;
+
+        // `buf` has been consumed, so an error writing its output is left for the next call
+        let _ = self.write_output();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_output()?;
+        self.get_mut().flush()
+    }
+}
+
+impl<W: Write> Drop for ConvertWriter<W> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            let _ = self.finish_conversion();
+        }
+    }
+}
+
+/// Decodes UTF-8 that may be split across buffers, holding the bytes of an incomplete char
+#[derive(Default)]
+struct Utf8Decoder {
+    partial: Vec<u8>,
+}
+
+impl Utf8Decoder {
+    /// Decode the chars of `bytes`, following any incomplete char from the previous buffer
+    fn decode<F: FnMut(char)>(&mut self, mut bytes: &[u8], mut f: F) -> io::Result<()> {
+        // Complete the partial char with the start of `bytes`
+        while !self.partial.is_empty() {
+            let (&byte, rest) = match bytes.split_first() {
+                Some(split) => split,
+                None => return Ok(()),
+            };
+            self.partial.push(byte);
+            bytes = rest;
+
+            match str::from_utf8(&self.partial) {
+                Ok(s) => {
+                    s.chars().for_each(&mut f);
+                    self.partial.clear();
+                }
+                Err(err) if err.error_len().is_some() => return Err(invalid_utf8()),
+                Err(_) => {}
+            }
+        }
+
+        let valid = match str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(err) if err.error_len().is_some() => return Err(invalid_utf8()),
+            Err(err) => {
+                let (valid, partial) = bytes.split_at(err.valid_up_to());
+                self.partial.extend_from_slice(partial);
+                // The bytes up to `valid_up_to` are valid UTF-8
+                str::from_utf8(valid).map_err(|_| invalid_utf8())?
+            }
+        };
+
+        valid.chars().for_each(f);
+        Ok(())
+    }
+
+    /// Check that the input didn't end partway through a char
+    fn finish(&mut self) -> io::Result<()> {
+        if self.partial.is_empty() {
+            Ok(())
+        } else {
+            self.partial.clear();
+            Err(invalid_utf8())
+        }
+    }
+}
+
+fn invalid_utf8() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+}
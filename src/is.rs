@@ -1,17 +1,15 @@
-use char_ext::{self, CharExt};
+use char_ext::{self, all_have_flag, any_flags, char_flags, CharExt};
+use char_set::CharSet;
 use constants;
-use std::ops::Range;
-
-fn all_in_ranges(input: &str, ranges: &[Range<u32>]) -> bool {
-    input.chars().all(|c| char_ext::is_in_ranges(c, ranges))
-}
 
 /// Test if `input` is [Romaji](https://en.wikipedia.org/wiki/Romaji) (allowing [Hepburn
-/// romanisation](https://en.wikipedia.org/wiki/Hepburn_romanization))
+/// romanisation](https://en.wikipedia.org/wiki/Hepburn_romanization) macrons and Kunrei-shiki
+/// circumflexes)
 ///
 /// ```rust
 /// # use wanakana::is_romaji;
 /// assert!(is_romaji("Tōkyō and Ōsaka"));
+/// assert!(is_romaji("Tôkyô"));
 /// assert!(is_romaji("12a*b&c-d"));
 /// assert!(!is_romaji("あアA"));
 /// assert!(!is_romaji("お願い"));
@@ -19,7 +17,7 @@ fn all_in_ranges(input: &str, ranges: &[Range<u32>]) -> bool {
 /// ```
 ///
 pub fn is_romaji(input: &str) -> bool {
-    all_in_ranges(input, constants::ROMAJI_RANGES)
+    all_have_flag(input, constants::ROMAJI_FLAG)
 }
 
 /// Test if `input` only includes [Kanji](https://en.wikipedia.org/wiki/Kanji),
@@ -36,7 +34,7 @@ pub fn is_romaji(input: &str) -> bool {
 /// assert!(!is_japanese("A"));
 /// ```
 pub fn is_japanese(input: &str) -> bool {
-    all_in_ranges(input, constants::JAPANESE_RANGES)
+    all_have_flag(input, constants::JAPANESE_FLAG)
 }
 
 /// Test if `input` is [Kana](https://en.wikipedia.org/wiki/Kana)
@@ -52,7 +50,7 @@ pub fn is_japanese(input: &str) -> bool {
 /// assert!(!is_kana("あAア"));
 /// ```
 pub fn is_kana(input: &str) -> bool {
-    all_in_ranges(input, constants::KANA_RANGES)
+    all_have_flag(input, constants::KANA_FLAG)
 }
 
 /// Test if `input` is [Hiragana](https://en.wikipedia.org/wiki/Hiragana)
@@ -64,7 +62,7 @@ pub fn is_kana(input: &str) -> bool {
 /// assert!(!is_hiragana("あア"));
 /// ```
 pub fn is_hiragana(input: &str) -> bool {
-    input.chars().all(CharExt::is_hiragana)
+    all_have_flag(input, constants::HIRAGANA_FLAG)
 }
 
 /// Test if `input` is [Katakana](https://en.wikipedia.org/wiki/Katakana))
@@ -77,22 +75,122 @@ pub fn is_hiragana(input: &str) -> bool {
 /// assert!(!is_katakana("あア"));
 /// ```
 pub fn is_katakana(input: &str) -> bool {
-    input.chars().all(CharExt::is_katakana)
+    all_have_flag(input, constants::KATAKANA_FLAG)
+}
+
+/// Which chars count as [Kanji](https://en.wikipedia.org/wiki/Kanji) for
+/// [`is_kanji_with_options`](fn.is_kanji_with_options.html)
+///
+/// The [CJK Unified Ideographs](https://en.wikipedia.org/wiki/CJK_Unified_Ideographs) block
+/// (U+4E00 to U+9FFF) always counts. Everything else counts by default, since all of it
+/// turns up in modern Japanese text, e.g. in names.
+///
+/// ```rust
+/// # use wanakana::{is_kanji_with_options, KanjiOptions};
+/// let unified_only = KanjiOptions {
+///     extension_a: false,
+///     supplementary: false,
+///     compatibility: false,
+///     marks: false,
+/// };
+/// assert!(is_kanji_with_options("漢字", &unified_only));
+/// assert!(!is_kanji_with_options("𠮷", &unified_only));
+/// assert!(!is_kanji_with_options("人々", &unified_only));
+///
+/// let options = KanjiOptions {
+///     marks: false,
+///     ..KanjiOptions::default()
+/// };
+/// assert!(is_kanji_with_options("𠮷", &options));
+/// assert!(!is_kanji_with_options("人々", &options));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KanjiOptions {
+    /// Count [CJK Unified Ideographs Extension
+    /// A](https://en.wikipedia.org/wiki/CJK_Unified_Ideographs_Extension_A) (U+3400 to U+4DBF),
+    /// e.g. "㐂"
+    pub extension_a: bool,
+
+    /// Count the ideographs outside of the Basic Multilingual Plane, in CJK Unified Ideographs
+    /// Extension B and later (from U+20000), e.g. "𠮷"
+    pub supplementary: bool,
+
+    /// Count the [CJK Compatibility
+    /// Ideographs](https://en.wikipedia.org/wiki/CJK_Compatibility_Ideographs) (U+F900 to U+FAFF,
+    /// and the supplement from U+2F800), e.g. "﨑"
+    pub compatibility: bool,
+
+    /// Count the marks used like Kanji: the [iteration
+    /// mark](https://en.wikipedia.org/wiki/Iteration_mark) "々" (as in "人々"), "〆" and "ヶ" (as in
+    /// "霞ヶ関")
+    pub marks: bool,
+}
+
+impl Default for KanjiOptions {
+    fn default() -> Self {
+        KanjiOptions {
+            extension_a: true,
+            supplementary: true,
+            compatibility: true,
+            marks: true,
+        }
+    }
+}
+
+impl KanjiOptions {
+    /// Test if `c` counts as Kanji with these options
+    pub(crate) fn includes(&self, c: char) -> bool {
+        char_ext::is_in_range(c, &constants::COMMON_CJK) ||
+            (self.extension_a && char_ext::is_in_range(c, &constants::RARE_CJK)) ||
+            (self.supplementary &&
+                char_ext::is_in_ranges(c, constants::SUPPLEMENTARY_CJK_RANGES)) ||
+            (self.compatibility &&
+                char_ext::is_in_ranges(c, constants::COMPATIBILITY_CJK_RANGES)) ||
+            (self.marks && char_ext::is_in_ranges(c, constants::KANJI_MARK_RANGES))
+    }
 }
 
 /// Tests if `input` is [Kanji](https://en.wikipedia.org/wiki/Kanji) ([Japanese CJK
 /// ideographs](https://en.wikipedia.org/wiki/CJK_Unified_Ideographs))
 ///
+/// This includes the rarer ideographs outside of the CJK Unified Ideographs block, and the marks
+/// used like Kanji, see [`KanjiOptions`](struct.KanjiOptions.html).
+///
 /// ```rust
 /// # use wanakana::is_kanji;
 /// assert!(is_kanji("刀"));
 /// assert!(is_kanji("切腹"));
+/// assert!(is_kanji("人々"));
+/// assert!(is_kanji("𠮷"));
+/// assert!(is_kanji("\u{31350}")); // CJK Unified Ideographs Extension H
+/// assert!(is_kanji("\u{2EBF0}")); // CJK Unified Ideographs Extension I
+/// assert!(is_kanji("﨑"));
 /// assert!(!is_kanji("勢い"));
 /// assert!(!is_kanji("あAア"));
 /// assert!(!is_kanji("🦀"));
 /// ```
 pub fn is_kanji(input: &str) -> bool {
-    input.chars().all(CharExt::is_kanji)
+    all_have_flag(input, constants::KANJI_FLAG)
+}
+
+/// Tests if `input` is [Kanji](https://en.wikipedia.org/wiki/Kanji), counting the chars chosen
+/// by `options`
+///
+/// ```rust
+/// # use wanakana::{is_kanji_with_options, KanjiOptions};
+/// let options = KanjiOptions {
+///     supplementary: false,
+///     ..KanjiOptions::default()
+/// };
+/// assert!(is_kanji_with_options("吉野", &options));
+/// assert!(!is_kanji_with_options("𠮷野", &options));
+/// ```
+pub fn is_kanji_with_options(input: &str, options: &KanjiOptions) -> bool {
+    if *options == KanjiOptions::default() {
+        is_kanji(input)
+    } else {
+        input.chars().all(|c| c.is_kanji_with_options(options))
+    }
 }
 
 /// Test if `input` contains a mix of [Romaji](https://en.wikipedia.org/wiki/Romaji) *and*
@@ -108,12 +206,209 @@ pub fn is_kanji(input: &str) -> bool {
 /// assert!(!is_mixed("あア", true));
 /// ```
 pub fn is_mixed(input: &str, pass_kanji: bool) -> bool {
-    // TODO: Could be more optimized. For now, this is using the same technique as the original.
-    let has_kanji = if pass_kanji {
-        false
-    } else {
-        input.chars().any(CharExt::is_kanji)
-    };
+    is_mixed_flags(any_flags(input), pass_kanji)
+}
+
+/// Test if the combined flags of some input are a mix of Romaji and Kana, see
+/// [`is_mixed`](fn.is_mixed.html)
+fn is_mixed_flags(flags: u8, pass_kanji: bool) -> bool {
+    let has_kanji = !pass_kanji && flags & constants::KANJI_FLAG != 0;
+
+    flags & constants::KANA_FLAG != 0 && flags & constants::ROMAJI_FLAG != 0 && !has_kanji
+}
+
+/// Chars to accept or reject on top of a predicate, for the `is_*_allowing` variants of the
+/// predicates, e.g. to validate a form field that takes Hiragana, spaces and hyphens
+///
+/// A char in `deny` is always rejected, even if it's also in `allow`. Both sets are empty by
+/// default, so the predicates behave like the plain ones.
+///
+/// ```rust
+/// # use wanakana::{is_hiragana_allowing, AllowedChars};
+/// let mut allowed = AllowedChars::default();
+/// allowed.allow.insert('-').insert_fn(char::is_whitespace);
+/// allowed.deny.insert('ー');
+///
+/// assert!(is_hiragana_allowing("やまだ たろう", &allowed));
+/// assert!(is_hiragana_allowing("ふじ-やま", &allowed));
+/// assert!(!is_hiragana_allowing("げーむ", &allowed));
+/// assert!(!is_hiragana_allowing("やまだ タロウ", &allowed));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AllowedChars {
+    /// Chars accepted in addition to the ones the predicate accepts
+    pub allow: CharSet,
+
+    /// Chars rejected even if the predicate (or `allow`) accepts them
+    pub deny: CharSet,
+}
+
+impl AllowedChars {
+    /// Test if `c` is accepted, given whether the predicate accepts it
+    fn accepts<F: Fn(char) -> bool>(&self, c: char, predicate: F) -> bool {
+        !self.deny.contains(c) && (predicate(c) || self.allow.contains(c))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.allow.is_empty() && self.deny.is_empty()
+    }
+}
+
+/// Test if every char of `input` is accepted by `predicate` or `allowed`
+fn all_allowed<F>(input: &str, allowed: &AllowedChars, predicate: F) -> bool
+where
+    F: Fn(char) -> bool,
+{
+    input.chars().all(|c| allowed.accepts(c, &predicate))
+}
+
+/// Test if `input` is [Romaji](https://en.wikipedia.org/wiki/Romaji), accepting or rejecting the
+/// chars in `allowed`, see [`is_romaji`](fn.is_romaji.html)
+///
+/// ```rust
+/// # use wanakana::{is_romaji_allowing, AllowedChars};
+/// let mut allowed = AllowedChars::default();
+/// allowed.allow.insert_str("！？");
+/// allowed.deny.insert_range('0'..='9');
+///
+/// assert!(is_romaji_allowing("nani！？", &allowed));
+/// assert!(!is_romaji_allowing("nani！？2", &allowed));
+/// ```
+pub fn is_romaji_allowing(input: &str, allowed: &AllowedChars) -> bool {
+    if allowed.is_empty() {
+        return is_romaji(input);
+    }
+
+    all_allowed(input, allowed, CharExt::is_romaji)
+}
+
+/// Test if `input` is Japanese, accepting or rejecting the chars in `allowed`, see
+/// [`is_japanese`](fn.is_japanese.html)
+///
+/// ```rust
+/// # use wanakana::{is_japanese_allowing, AllowedChars};
+/// let mut allowed = AllowedChars::default();
+/// allowed.allow.insert_str("!?");
+///
+/// assert!(is_japanese_allowing("泣き虫!", &allowed));
+/// assert!(!is_japanese_allowing("泣き虫.", &allowed));
+/// ```
+pub fn is_japanese_allowing(input: &str, allowed: &AllowedChars) -> bool {
+    if allowed.is_empty() {
+        return is_japanese(input);
+    }
+
+    all_allowed(input, allowed, CharExt::is_japanese)
+}
+
+/// Test if `input` is [Kana](https://en.wikipedia.org/wiki/Kana), accepting or rejecting the
+/// chars in `allowed`, see [`is_kana`](fn.is_kana.html)
+///
+/// ```rust
+/// # use wanakana::{is_kana_allowing, AllowedChars};
+/// let mut allowed = AllowedChars::default();
+/// allowed.allow.insert('　');
+///
+/// assert!(is_kana_allowing("やまだ　タロウ", &allowed));
+/// assert!(!is_kana_allowing("山田　タロウ", &allowed));
+/// ```
+pub fn is_kana_allowing(input: &str, allowed: &AllowedChars) -> bool {
+    if allowed.is_empty() {
+        return is_kana(input);
+    }
+
+    all_allowed(input, allowed, CharExt::is_kana)
+}
+
+/// Test if `input` is [Hiragana](https://en.wikipedia.org/wiki/Hiragana), accepting or rejecting
+/// the chars in `allowed`, see [`is_hiragana`](fn.is_hiragana.html)
+///
+/// ```rust
+/// # use wanakana::{is_hiragana_allowing, AllowedChars};
+/// let mut allowed = AllowedChars::default();
+/// allowed.allow.insert_fn(char::is_whitespace);
+///
+/// assert!(is_hiragana_allowing("やまだ たろう", &allowed));
+/// assert!(!is_hiragana_allowing("やまだ タロウ", &allowed));
+/// ```
+pub fn is_hiragana_allowing(input: &str, allowed: &AllowedChars) -> bool {
+    if allowed.is_empty() {
+        return is_hiragana(input);
+    }
+
+    all_allowed(input, allowed, CharExt::is_hiragana)
+}
+
+/// Test if `input` is [Katakana](https://en.wikipedia.org/wiki/Katakana), accepting or rejecting
+/// the chars in `allowed`, see [`is_katakana`](fn.is_katakana.html)
+///
+/// ```rust
+/// # use wanakana::{is_katakana_allowing, AllowedChars};
+/// let mut allowed = AllowedChars::default();
+/// allowed.allow.insert('=');
+/// allowed.deny.insert('ヶ');
+///
+/// assert!(is_katakana_allowing("ジョン=スミス", &allowed));
+/// assert!(!is_katakana_allowing("ヶ", &allowed));
+/// ```
+pub fn is_katakana_allowing(input: &str, allowed: &AllowedChars) -> bool {
+    if allowed.is_empty() {
+        return is_katakana(input);
+    }
+
+    all_allowed(input, allowed, CharExt::is_katakana)
+}
+
+/// Test if `input` is [Kanji](https://en.wikipedia.org/wiki/Kanji), accepting or rejecting the
+/// chars in `allowed`, see [`is_kanji`](fn.is_kanji.html)
+///
+/// ```rust
+/// # use wanakana::{is_kanji_allowing, AllowedChars};
+/// let mut allowed = AllowedChars::default();
+/// allowed.allow.insert('・');
+/// // The supplementary ideographic planes
+/// allowed.deny.insert_range('\u{20000}'..='\u{3FFFF}');
+///
+/// assert!(is_kanji_allowing("東京・大阪", &allowed));
+/// assert!(!is_kanji_allowing("𠮷野", &allowed));
+/// ```
+pub fn is_kanji_allowing(input: &str, allowed: &AllowedChars) -> bool {
+    if allowed.is_empty() {
+        return is_kanji(input);
+    }
+
+    all_allowed(input, allowed, CharExt::is_kanji)
+}
+
+/// Test if `input` contains a mix of [Romaji](https://en.wikipedia.org/wiki/Romaji) *and*
+/// [Kana](https://en.wikipedia.org/wiki/Kana), see [`is_mixed`](fn.is_mixed.html)
+///
+/// The chars in `allowed.allow` are ignored, so an allowed Kanji doesn't fail the test even if
+/// `pass_kanji` is `false`. Any char in `allowed.deny` fails the test.
+///
+/// ```rust
+/// # use wanakana::{is_mixed_allowing, AllowedChars};
+/// let mut allowed = AllowedChars::default();
+/// allowed.allow.insert('腹');
+/// allowed.deny.insert('!');
+///
+/// assert!(is_mixed_allowing("お腹A", false, &allowed));
+/// assert!(!is_mixed_allowing("お腹A!", false, &allowed));
+/// assert!(!is_mixed_allowing("お茶A", false, &allowed));
+/// ```
+pub fn is_mixed_allowing(input: &str, pass_kanji: bool, allowed: &AllowedChars) -> bool {
+    if allowed.is_empty() {
+        return is_mixed(input, pass_kanji);
+    }
+
+    let mut flags = 0;
+    for c in input.chars() {
+        if allowed.deny.contains(c) {
+            return false;
+        } else if !allowed.allow.contains(c) {
+            flags |= char_flags(c);
+        }
+    }
 
-    input.chars().any(CharExt::is_kana) && input.chars().any(CharExt::is_romaji) && !has_kanji
+    is_mixed_flags(flags, pass_kanji)
 }
@@ -1,5 +1,12 @@
 use char_ext::CharExt;
 use constants;
+use halfwidth_katakana::{is_halfwidth_kana, HalfwidthToKatakana};
+use options::{ConversionOptions, LongVowels};
+use output;
+use std::borrow::Cow;
+use std::char;
+use std::fmt;
+use std::iter;
 
 /// Convert [Katakana](https://en.wikipedia.org/wiki/Katakana) to
 /// [Hiragana](https://en.wikipedia.org/wiki/Hiragana)
@@ -11,47 +18,162 @@ use constants;
 ///     katakana_to_hiragana("カタカナ is a type of kana"),
 ///     "かたかな is a type of kana"
 /// );
+/// assert_eq!(katakana_to_hiragana("ｹﾞｰﾑ"), "げえむ"); // Half-width katakana is converted too
+/// assert_eq!(katakana_to_hiragana("ヴァヷ"), "ゔぁヷ"); // ヷ, ヸ, ヹ and ヺ have no hiragana
 /// ```
 pub fn katakana_to_hiragana(input: &str) -> String {
-    // TODO: This is mostly a 1-to-1 port, can probably be improved
-    let mut hira = String::with_capacity(input.len());
+    katakana_to_hiragana_with_options(input, &ConversionOptions::default())
+}
+
+/// Convert [Katakana](https://en.wikipedia.org/wiki/Katakana) to
+/// [Hiragana](https://en.wikipedia.org/wiki/Hiragana), using the given options
+///
+/// ```rust
+/// # use wanakana::{katakana_to_hiragana_with_options, ConversionOptions, LongVowels};
+/// let options = ConversionOptions {
+///     long_vowels: LongVowels::Keep,
+///     ..ConversionOptions::default()
+/// };
+/// assert_eq!(katakana_to_hiragana_with_options("ゲーム", &options), "げーむ");
+/// ```
+pub fn katakana_to_hiragana_with_options(input: &str, options: &ConversionOptions) -> String {
+    output::to_string(input.len(), |hira| {
+        katakana_to_hiragana_into_with_options(input, options, hira)
+    })
+}
+
+pub(crate) fn katakana_to_hiragana_into_with_options<W: fmt::Write>(
+    input: &str,
+    options: &ConversionOptions,
+    out: &mut W,
+) -> fmt::Result {
+    let mut hira = HiraganaWriter::new(out, options);
+
+    // Half-width kana has to be made full-width first, which can join two chars into one
+    if !input.chars().any(is_halfwidth_kana) {
+        return input.chars().try_for_each(|c| hira.push(c));
+    }
+
+    let mut halfwidth = HalfwidthToKatakana::default();
+
+    for c in input.chars() {
+        halfwidth.push(c, &mut hira)?;
+    }
+
+    halfwidth.finish(&mut hira)
+}
+
+/// A writer that converts the full-width Katakana written to it to Hiragana, before writing it to
+/// the inner writer
+struct HiraganaWriter<'a, W: 'a> {
+    out: &'a mut W,
+    options: &'a ConversionOptions,
+    katakana: KatakanaToHiragana,
+}
+
+impl<'a, W: fmt::Write> HiraganaWriter<'a, W> {
+    fn new(out: &'a mut W, options: &'a ConversionOptions) -> Self {
+        HiraganaWriter {
+            out,
+            options,
+            katakana: KatakanaToHiragana::default(),
+        }
+    }
 
-    let mut previous_kana: Option<char> = None;
+    fn push(&mut self, c: char) -> fmt::Result {
+        match self.katakana.convert(c, self.options) {
+            Some(hira) => self.out.write_char(hira),
+            None => Ok(()),
+        }
+    }
+}
 
-    for (index, c) in input.chars().enumerate() {
+impl<'a, W: fmt::Write> fmt::Write for HiraganaWriter<'a, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.push(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c)
+    }
+}
+
+/// Katakana to Hiragana conversion one char at a time, remembering the previous kana so that a
+/// following long vowel mark can be expanded. Half-width Katakana should be normalized first.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct KatakanaToHiragana {
+    previous_kana: Option<char>,
+}
+
+impl KatakanaToHiragana {
+    /// Convert the next char, returning `None` if it's a long vowel mark that can't be expanded
+    pub(crate) fn convert(&mut self, c: char, options: &ConversionOptions) -> Option<char> {
         let is_long_dash = (c as u32) == constants::PROLONGED_SOUND_MARK;
+        let offset = constants::KATAKANA_START - constants::HIRAGANA_START;
+        let katakana_end = constants::HIRAGANA_END + offset;
 
-        if (c as u32) == constants::KANA_SLASH_DOT || (is_long_dash && index < 1) {
-            hira.push(c);
-        } else if previous_kana.is_some() && is_long_dash && index > 0 {
+        if (c as u32) == constants::KANA_SLASH_DOT {
+            Some(c)
+        } else if is_long_dash && options.long_vowels == LongVowels::Keep {
+            self.previous_kana = None;
+            Some(c)
+        } else if is_long_dash && self.previous_kana.is_some() {
             // Transform long vowels: 'オー' to 'おう'
             // Transform previous_kana back to romaji, and slice off the vowel
-            let long_vowel = previous_kana
-                .and_then(|kana| {
-                    constants::TO_ROMAJI.get::<str>(kana.to_string().as_ref())
-                })
-                .and_then(|romaji| romaji.chars().rev().next())
+            let mut buffer = [0; 4];
+            self.previous_kana
+                .and_then(|kana| constants::TO_ROMAJI_TRIE.get(iter::once(kana)))
+                .and_then(|romaji| romaji.chars().next_back())
                 .and_then(|vowel| {
-                    constants::LONG_VOWELS.get::<str>(vowel.to_string().as_ref())
-                });
-
-            if let Some(vowel) = long_vowel {
-                hira.push_str(vowel);
-            }
-        } else if !is_long_dash && c.is_katakana() {
+                    constants::LONG_VOWELS.get::<str>(vowel.encode_utf8(&mut buffer))
+                })
+                .and_then(|vowel| vowel.chars().next())
+        } else if !is_long_dash && c.is_katakana() && (c as u32) <= katakana_end {
             // Shift charcode.
-            let code = c as u32 + constants::HIRAGANA_START - constants::KATAKANA_START;
-
-            if let Some(hira_char) = ::std::char::from_u32(code) {
-                hira.push(hira_char);
-                previous_kana = Some(hira_char);
-            }
+            let code = c as u32 - offset;
+            let hira = char::from_u32(code).unwrap_or(c);
+            self.previous_kana = Some(hira);
+            Some(hira)
         } else {
-            // Pass non katakana chars through
-            hira.push(c);
-            previous_kana = None;
+            // Pass non katakana chars through, as well as ヷ, ヸ, ヹ and ヺ, which have no
+            // hiragana
+            self.previous_kana = None;
+            Some(c)
         }
     }
+}
+
+/// Convert [Katakana](https://en.wikipedia.org/wiki/Katakana) to
+/// [Hiragana](https://en.wikipedia.org/wiki/Hiragana), borrowing `input` if it has no Katakana
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use wanakana::katakana_to_hiragana_cow;
+/// assert_eq!(katakana_to_hiragana_cow("カタカナ"), "かたかな");
+/// assert!(matches!(katakana_to_hiragana_cow("ひらがな"), Cow::Borrowed("ひらがな")));
+/// ```
+pub fn katakana_to_hiragana_cow(input: &str) -> Cow<'_, str> {
+    katakana_to_hiragana_cow_with_options(input, &ConversionOptions::default())
+}
+
+/// Convert [Katakana](https://en.wikipedia.org/wiki/Katakana) to
+/// [Hiragana](https://en.wikipedia.org/wiki/Hiragana), writing the result to `out`
+pub fn katakana_to_hiragana_into<W: fmt::Write>(input: &str, out: &mut W) -> fmt::Result {
+    katakana_to_hiragana_into_with_options(input, &ConversionOptions::default(), out)
+}
+
+pub(crate) fn katakana_to_hiragana_cow_with_options<'a>(
+    input: &'a str,
+    options: &ConversionOptions,
+) -> Cow<'a, str> {
+    if input.chars().any(converts_katakana_to_hiragana) {
+        Cow::Owned(katakana_to_hiragana_with_options(input, options))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
 
-    hira
+/// Test if converting `c` from Katakana to Hiragana could change it
+pub(crate) fn converts_katakana_to_hiragana(c: char) -> bool {
+    c.is_katakana() || is_halfwidth_kana(c)
 }
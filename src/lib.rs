@@ -3,9 +3,61 @@ extern crate phf;
 mod constants;
 mod is;
 mod char_ext;
+mod char_set;
+mod classify;
+mod composer;
+mod converter;
 mod katakana_to_hiragana;
+mod halfwidth_katakana;
 mod hiragana_to_katakana;
+mod in_place;
+mod io;
+mod mapping;
+mod options;
+mod output;
+mod passport;
+mod romanization;
+mod stream;
+mod str_ext;
+mod strip_okurigana;
+mod to_hiragana;
+mod to_kana;
+mod to_katakana;
+mod to_romaji;
+mod tokenize;
+mod trie;
+mod width;
 
-pub use hiragana_to_katakana::hiragana_to_katakana;
-pub use is::{is_hiragana, is_japanese, is_kana, is_kanji, is_katakana, is_mixed, is_romaji};
-pub use katakana_to_hiragana::katakana_to_hiragana;
+pub use char_ext::CharExt;
+pub use char_set::CharSet;
+pub use classify::{classify, CharClass, KanaKind, KanjiKind, Script, UNICODE_VERSION};
+pub use composer::Composer;
+pub use converter::Converter;
+pub use halfwidth_katakana::{halfwidth_to_katakana, halfwidth_to_katakana_cow,
+                             halfwidth_to_katakana_into, katakana_to_halfwidth,
+                             katakana_to_halfwidth_cow, katakana_to_halfwidth_into};
+pub use hiragana_to_katakana::{hiragana_to_katakana, hiragana_to_katakana_cow,
+                               hiragana_to_katakana_into, hiragana_to_katakana_with_options};
+pub use in_place::{make_hiragana_in_place, make_katakana_in_place};
+pub use io::{ConvertReader, ConvertWriter};
+pub use is::{is_hiragana, is_hiragana_allowing, is_japanese, is_japanese_allowing, is_kana,
+             is_kana_allowing, is_kanji, is_kanji_allowing, is_kanji_with_options, is_katakana,
+             is_katakana_allowing, is_mixed, is_mixed_allowing, is_romaji, is_romaji_allowing,
+             AllowedChars, KanjiOptions};
+pub use katakana_to_hiragana::{katakana_to_hiragana, katakana_to_hiragana_cow,
+                               katakana_to_hiragana_into, katakana_to_hiragana_with_options};
+pub use mapping::CustomMapping;
+pub use options::{ConversionOptions, LongO, LongVowelStyle, LongVowels};
+pub use passport::{to_passport_name, to_passport_romaji, NameOrder, PassportOptions};
+pub use romanization::RomanizationSystem;
+pub use stream::{Conversion, ConvertChars, Converted};
+pub use str_ext::StrExt;
+pub use strip_okurigana::{strip_okurigana, strip_okurigana_matching};
+pub use to_hiragana::{to_hiragana, to_hiragana_cow, to_hiragana_into, to_hiragana_with_options};
+pub use to_kana::{to_kana, to_kana_cow, to_kana_into, to_kana_with_options};
+pub use to_katakana::{to_katakana, to_katakana_cow, to_katakana_into, to_katakana_with_options};
+pub use to_romaji::{to_romaji, to_romaji_cow, to_romaji_into, to_romaji_with_options};
+pub use tokenize::{tokenize, tokenize_compact, Token, TokenKind, Tokens};
+pub use width::{to_fullwidth, to_fullwidth_cow, to_fullwidth_into, to_fullwidth_with_options,
+                to_halfwidth, to_halfwidth_cow, to_halfwidth_into, to_halfwidth_with_options,
+                WidthOptions};
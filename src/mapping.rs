@@ -0,0 +1,100 @@
+use std::cmp;
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+/// User-supplied entries layered over one of the built-in mapping tables at runtime.
+///
+/// Entries can be added (overriding any built-in entry with the same key) or removed, in which
+/// case the built-in entry is no longer matched and shorter matches are tried instead. Romaji is
+/// matched regardless of case, so ASCII letters in keys are lowercased.
+///
+/// ```rust
+/// # use wanakana::{to_kana_with_options, ConversionOptions, CustomMapping};
+/// let mut mapping = CustomMapping::new();
+/// mapping.insert("la", "ぁ").insert("ou", "おー").remove("ca");
+///
+/// let options = ConversionOptions {
+///     custom_kana_mapping: mapping,
+///     ..ConversionOptions::default()
+/// };
+/// assert_eq!(to_kana_with_options("la", &options), "ぁ");
+/// assert_eq!(to_kana_with_options("ousama", &options), "おーさま");
+/// assert_eq!(to_kana_with_options("ca", &options), "cあ");
+///
+/// let mut obsolete = ConversionOptions::default();
+/// obsolete.custom_kana_mapping.insert("Wi", "ゐ");
+/// assert_eq!(to_kana_with_options("wi Wi", &obsolete), "ゐ ヰ");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CustomMapping {
+    entries: HashMap<String, Option<String>>,
+    max_key_len: usize,
+}
+
+impl CustomMapping {
+    /// Create an empty mapping, which leaves the built-in table unchanged
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map `key` to `value`, overriding any built-in entry for `key`
+    pub fn insert<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.set(key.into(), Some(value.into()))
+    }
+
+    /// Remove the entry for `key`, including any built-in entry
+    pub fn remove<K: Into<String>>(&mut self, key: K) -> &mut Self {
+        self.set(key.into(), None)
+    }
+
+    /// Test if this mapping has no entries, and therefore doesn't change the built-in table
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn set(&mut self, mut key: String, value: Option<String>) -> &mut Self {
+        key.make_ascii_lowercase();
+        self.max_key_len = cmp::max(self.max_key_len, key.chars().count());
+        self.entries.insert(key, value);
+        self
+    }
+
+    /// Look up `key`, returning `None` if the built-in table should be used, or `Some(None)` if
+    /// the entry has been removed
+    pub(crate) fn get(&self, key: &str) -> Option<Option<&str>> {
+        self.entries.get(key).map(|value| value.as_ref().map(String::as_str))
+    }
+
+    /// The keys that have been added (rather than removed) by this mapping
+    pub(crate) fn inserted_keys(&self) -> impl Iterator<Item = &str> {
+        self.entries
+            .iter()
+            .filter(|&(_, value)| value.is_some())
+            .map(|(key, _)| key.as_str())
+    }
+
+    /// The length (in chars) of the longest key in this mapping
+    pub(crate) fn max_key_len(&self) -> usize {
+        self.max_key_len
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for CustomMapping
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut mapping = CustomMapping::new();
+
+        for (key, value) in iter {
+            mapping.insert(key, value);
+        }
+
+        mapping
+    }
+}
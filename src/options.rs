@@ -0,0 +1,119 @@
+use mapping::CustomMapping;
+use romanization::RomanizationSystem;
+
+/// Options shared by all of the converters in this crate.
+///
+/// The default options produce the same output as the plain (non-`_with_options`) converters.
+///
+/// ```rust
+/// # use wanakana::{to_kana_with_options, ConversionOptions};
+/// let options = ConversionOptions {
+///     use_obsolete_kana: true,
+///     ..ConversionOptions::default()
+/// };
+///
+/// assert_eq!(to_kana_with_options("wiwe", &options), "ゐゑ");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConversionOptions {
+    /// Use obsolete kana, converting "wi" to ゐ and "we" to ゑ
+    pub use_obsolete_kana: bool,
+
+    /// Pass romaji through when converting mixed input to hiragana or katakana
+    pub pass_romaji: bool,
+
+    /// Convert katakana to uppercase romaji when converting to romaji
+    pub upcase_katakana: bool,
+
+    /// Convert input as it would be typed into an IME, holding on to a trailing "n" (and "ny")
+    /// until the following input decides between ん and the な-row
+    pub ime_mode: bool,
+
+    /// The romanization system used when converting to romaji
+    pub romanization: RomanizationSystem,
+
+    /// How the katakana prolonged sound mark ー is handled when converting to hiragana
+    pub long_vowels: LongVowels,
+
+    /// How long vowels are written when converting to romaji
+    pub long_vowel_style: LongVowelStyle,
+
+    /// How a long "o" written with a macron or circumflex (ō or ô) is converted to hiragana
+    pub long_o: LongO,
+
+    /// Romaji to kana mappings layered over the built-in ones. Keys should be lowercase romaji.
+    pub custom_kana_mapping: CustomMapping,
+
+    /// Kana to romaji mappings layered over the built-in ones. Keys should be hiragana, since
+    /// katakana is converted to hiragana before being romanized.
+    pub custom_romaji_mapping: CustomMapping,
+}
+
+/// Policy for the katakana prolonged sound mark ー when converting to hiragana
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LongVowels {
+    /// Expand ー into the vowel it lengthens, e.g. "オー" becomes "おう"
+    #[default]
+    Expand,
+
+    /// Keep ー as-is, e.g. "オー" becomes "おー"
+    Keep,
+}
+
+/// How a long "o" written with a macron or circumflex (ō or ô) is converted to hiragana. In
+/// katakana, it's always converted to "オー".
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LongO {
+    /// Convert to "おう", as in "とうきょう" (Tōkyō)
+    #[default]
+    Ou,
+
+    /// Convert to "おお", as in "おおさか" (Ōsaka)
+    Oo,
+}
+
+/// How long vowels are written when converting to romaji
+///
+/// Long vowels are a vowel followed by the katakana prolonged sound mark ー, or by あ, う or え
+/// after the same vowel, or by お or う after an "o". As in Hepburn romanization, "ii" and "ei" are
+/// always written as-is.
+///
+/// ```rust
+/// # use wanakana::{to_romaji_with_options, ConversionOptions, LongVowelStyle};
+/// let romanize = |input, long_vowel_style| {
+///     let options = ConversionOptions {
+///         long_vowel_style,
+///         ..ConversionOptions::default()
+///     };
+///     to_romaji_with_options(input, &options)
+/// };
+///
+/// assert_eq!(romanize("とうきょう", LongVowelStyle::Wapuro), "toukyou");
+/// assert_eq!(romanize("とうきょう", LongVowelStyle::Doubled), "tookyoo");
+/// assert_eq!(romanize("とうきょう", LongVowelStyle::Macron), "tōkyō");
+/// assert_eq!(romanize("とうきょう", LongVowelStyle::Circumflex), "tôkyô");
+/// assert_eq!(romanize("とうきょう", LongVowelStyle::Omitted), "tokyo");
+///
+/// assert_eq!(romanize("コーヒー", LongVowelStyle::Wapuro), "kouhii");
+/// assert_eq!(romanize("コーヒー", LongVowelStyle::Macron), "kōhī");
+/// assert_eq!(romanize("おおさか", LongVowelStyle::Macron), "ōsaka");
+/// assert_eq!(romanize("いいえ", LongVowelStyle::Macron), "iie");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LongVowelStyle {
+    /// Spell long vowels as they're written in kana, e.g. "toukyou"
+    #[default]
+    Wapuro,
+
+    /// Write long vowels as a doubled vowel, e.g. "tookyoo"
+    Doubled,
+
+    /// Write long vowels with a macron, e.g. "tōkyō"
+    Macron,
+
+    /// Write long vowels with a circumflex, e.g. "tôkyô"
+    Circumflex,
+
+    /// Write long vowels as short vowels, as on signage, e.g. "tokyo"
+    Omitted,
+}
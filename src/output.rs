@@ -0,0 +1,20 @@
+use std::fmt;
+
+/// Collect the output of a conversion that writes to any `fmt::Write` into a new `String`
+pub(crate) fn to_string<F>(capacity: usize, write: F) -> String
+where
+    F: FnOnce(&mut String) -> fmt::Result,
+{
+    let mut output = String::with_capacity(capacity);
+    push_to(&mut output, write);
+    output
+}
+
+/// Append the output of a conversion that writes to any `fmt::Write` to `output`
+pub(crate) fn push_to<F>(output: &mut String, write: F)
+where
+    F: FnOnce(&mut String) -> fmt::Result,
+{
+    // Unlike other writers, a `String` can't fail
+    write(output).expect("failed to write to a String");
+}
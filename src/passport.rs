@@ -0,0 +1,145 @@
+use mapping::CustomMapping;
+use options::{ConversionOptions, LongVowels};
+use to_romaji::to_romaji_with_options;
+
+/// Options for [`to_passport_romaji`](fn.to_passport_romaji.html) and
+/// [`to_passport_name`](fn.to_passport_name.html)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PassportOptions {
+    /// Write long "o" sounds (おお and おう) as "OH" instead of "O", e.g. "OHNO" instead of "ONO"
+    pub oh: bool,
+
+    /// The order the family and given names are written in
+    pub name_order: NameOrder,
+}
+
+/// The order of family and given names in [`to_passport_name`](fn.to_passport_name.html)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NameOrder {
+    /// Family name first, e.g. "YAMADA TARO"
+    #[default]
+    FamilyFirst,
+
+    /// Given name first, e.g. "TARO YAMADA"
+    GivenFirst,
+}
+
+/// Convert a [Kana](https://en.wikipedia.org/wiki/Kana) name to the
+/// [Hepburn](https://en.wikipedia.org/wiki/Hepburn_romanization) romaji used on Japanese
+/// passports
+///
+/// Passport romaji is uppercase and has no macrons or apostrophes. Long "o" and "u" sounds are
+/// written as a single vowel (or "OH" for long "o" if `options.oh` is set), ん before "b", "m" and
+/// "p" is written as "M", and っち is written as "TCH". ぢ and づ are written as "JI" and "ZU", を
+/// as "O", and ヴ as the "B" row.
+///
+/// ```rust
+/// # use wanakana::{to_passport_romaji, PassportOptions};
+/// let options = PassportOptions::default();
+/// assert_eq!(to_passport_romaji("さとう", &options), "SATO");
+/// assert_eq!(to_passport_romaji("おおの", &options), "ONO");
+/// assert_eq!(to_passport_romaji("ゆうこ", &options), "YUKO");
+/// assert_eq!(to_passport_romaji("なんば", &options), "NAMBA");
+/// assert_eq!(to_passport_romaji("ほんま", &options), "HOMMA");
+/// assert_eq!(to_passport_romaji("はっちょう", &options), "HATCHO");
+/// assert_eq!(to_passport_romaji("しんいち", &options), "SHINICHI");
+/// assert_eq!(to_passport_romaji("ケイコ", &options), "KEIKO");
+/// assert_eq!(to_passport_romaji("ちぢわ", &options), "CHIJIWA");
+/// assert_eq!(to_passport_romaji("つづき", &options), "TSUZUKI");
+/// assert_eq!(to_passport_romaji("かをる", &options), "KAORU");
+/// assert_eq!(to_passport_romaji("ヴィクトル", &options), "BIKUTORU");
+///
+/// let oh = PassportOptions {
+///     oh: true,
+///     ..PassportOptions::default()
+/// };
+/// assert_eq!(to_passport_romaji("おおの", &oh), "OHNO");
+/// assert_eq!(to_passport_romaji("ようこ", &oh), "YOHKO");
+/// ```
+pub fn to_passport_romaji(name: &str, options: &PassportOptions) -> String {
+    let romaji_options = ConversionOptions {
+        long_vowels: LongVowels::Keep,
+        custom_romaji_mapping: passport_mapping(),
+        ..ConversionOptions::default()
+    };
+    let romaji: Vec<char> = to_romaji_with_options(name, &romaji_options)
+        .chars()
+        .filter(|&c| c != '\'')
+        .collect();
+    let mut passport = String::with_capacity(romaji.len());
+    let mut index = 0;
+
+    while index < romaji.len() {
+        let c = romaji[index];
+        let next = romaji.get(index + 1).cloned();
+
+        match (c, next) {
+            // Long "o" and "u" are written as a single vowel
+            ('o', Some('o')) | ('o', Some('u')) => {
+                passport.push('O');
+                index += 1;
+
+                // "OH" isn't used before another "H", e.g. "SHOHEI" rather than "SHOHHEI"
+                if options.oh && romaji.get(index + 1) != Some(&'h') {
+                    passport.push('H');
+                }
+            }
+            ('u', Some('u')) => {
+                passport.push('U');
+                index += 1;
+            }
+            // ん before a labial consonant is written as "M"
+            ('n', Some('b')) | ('n', Some('m')) | ('n', Some('p')) => passport.push('M'),
+            // The prolonged sound mark isn't written
+            ('-', _) => {}
+            _ => passport.extend(c.to_uppercase()),
+        }
+
+        index += 1;
+    }
+
+    passport
+}
+
+/// The passport spellings that differ from the default Hepburn table, keyed by hiragana since
+/// katakana is converted to hiragana before being romanized
+fn passport_mapping() -> CustomMapping {
+    vec![
+        ("ぢ", "ji"),
+        ("ぢゃ", "ja"),
+        ("ぢゅ", "ju"),
+        ("ぢょ", "jo"),
+        ("づ", "zu"),
+        ("を", "o"),
+        ("ゔ", "bu"),
+        ("ゔぁ", "ba"),
+        ("ゔぃ", "bi"),
+        ("ゔぇ", "be"),
+        ("ゔぉ", "bo"),
+    ].into_iter()
+        .collect()
+}
+
+/// Convert a family and given name in [Kana](https://en.wikipedia.org/wiki/Kana) to passport
+/// romaji, see [`to_passport_romaji`](fn.to_passport_romaji.html)
+///
+/// ```rust
+/// # use wanakana::{to_passport_name, NameOrder, PassportOptions};
+/// let options = PassportOptions::default();
+/// assert_eq!(to_passport_name("やまだ", "たろう", &options), "YAMADA TARO");
+///
+/// let options = PassportOptions {
+///     oh: true,
+///     name_order: NameOrder::GivenFirst,
+/// };
+/// assert_eq!(to_passport_name("おおの", "しょうへい", &options), "SHOHEI OHNO");
+/// ```
+pub fn to_passport_name(family_name: &str, given_name: &str, options: &PassportOptions) -> String {
+    let family_name = to_passport_romaji(family_name, options);
+    let given_name = to_passport_romaji(given_name, options);
+
+    match options.name_order {
+        NameOrder::FamilyFirst => format!("{} {}", family_name, given_name),
+        NameOrder::GivenFirst => format!("{} {}", given_name, family_name),
+    }
+}
@@ -0,0 +1,106 @@
+// Hand-written char ranges, for the classes of chars the Unicode Character Database doesn't
+// distinguish. The build script includes this file too, and combines these with the ranges it
+// derives from the UCD files in ucd/ (see `write_char_ranges` in build.rs).
+
+use std::ops::Range;
+
+type Ranges = &'static [Range<u32>];
+
+macro_rules! range {
+    ($start:expr, $end:expr) => {
+        Range { start: $start, end: ($end + 1), }
+    }
+}
+
+macro_rules! range_const {
+    ($name:ident, $start:expr, $end:expr) => {
+        pub(crate) const $name: Range<u32> = range!($start, $end);
+    }
+}
+
+macro_rules! u32_const {
+    ($name:ident, $value:expr) => {
+        pub(crate) const $name: u32 = $value;
+    }
+}
+
+range_const!(ZENKAKU_NUMBERS, 0xFF10, 0xFF19);
+range_const!(LATIN_NUMBERS, 0x0030, 0x0039);
+
+/// Japanese punctuation outside of the CJK Symbols and Punctuation block and the half-width kana
+/// punctuation, e.g. "・", "！" and "￥"
+#[allow(dead_code)] // only used by the build script
+pub(crate) const FULLWIDTH_PUNCTUATION_RANGES: Ranges = &[
+    range!(0x30FB, 0x30FC), // ・ ー
+    range!(0xFF01, 0xFF0F), // ！ to ／
+    range!(0xFF1A, 0xFF1F), // ： to ？
+    range!(0xFF3B, 0xFF3F), // ［ to ＿
+    range!(0xFF5B, 0xFF60), // ｛ to ｠
+    range!(0xFFE0, 0xFFEE), // ￠ to ￮
+];
+
+pub(crate) const SMART_QUOTE_RANGES: Ranges = &[
+    range!(0x2018, 0x2019), // ‘ ’
+    range!(0x201C, 0x201D), // “ ”
+];
+
+/// Latin letters with marks used in romaji, in addition to the Basic Latin block
+///
+/// Includes upper/lowercase long vowels like "ā, ī, ū, ē, ō" (and the Kunrei-shiki "â, î, û, ê, ô")
+#[allow(dead_code)] // only used by the build script
+pub(crate) const ROMAJI_MARK_RANGES: Ranges = &[
+    // HEPBURN_MACRON_RANGES
+    range!(0x0100, 0x0101), // Ā ā
+    range!(0x0112, 0x0113), // Ē ē
+    range!(0x012a, 0x012b), // Ī ī
+    range!(0x014c, 0x014d), // Ō ō
+    range!(0x016a, 0x016b), // Ū ū
+
+    // KUNREI_CIRCUMFLEX_RANGES
+    range!(0x00c2, 0x00c2), // Â
+    range!(0x00ca, 0x00ca), // Ê
+    range!(0x00ce, 0x00ce), // Î
+    range!(0x00d4, 0x00d4), // Ô
+    range!(0x00db, 0x00db), // Û
+    range!(0x00e2, 0x00e2), // â
+    range!(0x00ea, 0x00ea), // ê
+    range!(0x00ee, 0x00ee), // î
+    range!(0x00f4, 0x00f4), // ô
+    range!(0x00fb, 0x00fb), // û
+];
+
+/// Marks used like Kanji: the iteration mark 々 (as in 人々), 〆 (しめ) and ヶ (as in 霞ヶ関)
+pub(crate) const KANJI_MARK_RANGES: Ranges = &[
+    range!(0x3005, 0x3006), // 々 〆
+    range!(0x30F6, 0x30F6), // ヶ
+];
+
+/// CJK Unified Ideographs extensions newer than the UCD files in ucd/, by block name; every char
+/// in these is assigned. Stopgap until ucd/ is upgraded to Unicode 15.1, which has both blocks:
+/// the build script then fails until this is removed.
+#[allow(dead_code)] // only used by the build script
+pub(crate) const LATER_CJK_EXTENSIONS: &[(&str, Range<u32>)] = &[
+    ("CJK Unified Ideographs Extension H", range!(0x31350, 0x323AF)), // Unicode 15.0
+    ("CJK Unified Ideographs Extension I", range!(0x2EBF0, 0x2EE5D)), // Unicode 15.1
+];
+
+pub(crate) const EN_PUNCTUATION_RANGES: Ranges = &[
+    range!(0x21, 0x2F),
+    range!(0x3A, 0x3F),
+    range!(0x5B, 0x60),
+    range!(0x7B, 0x7E),
+
+    // SMART_QUOTE_RANGES
+    range!(0x2018, 0x2019), // ‘ ’
+    range!(0x201C, 0x201D), // “ ”
+];
+
+u32_const!(ZENKAKU_ASCII_START, 0xFF01);
+u32_const!(ZENKAKU_ASCII_END, 0xFF5E);
+u32_const!(ZENKAKU_ASCII_OFFSET, 0xFEE0);
+u32_const!(IDEOGRAPHIC_SPACE, 0x3000);
+// Katakana continue through the Common script ・ and ー (KATAKANA_START is derived from the UCD)
+u32_const!(KATAKANA_END, 0x30FC);
+u32_const!(PROLONGED_SOUND_MARK, 0x30FC);
+u32_const!(HANKAKU_PROLONGED_SOUND_MARK, 0xFF70);
+u32_const!(KANA_SLASH_DOT, 0x30FB);
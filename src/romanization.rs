@@ -0,0 +1,100 @@
+use constants;
+
+/// The [romanization system](https://en.wikipedia.org/wiki/Romanization_of_Japanese) used when
+/// converting kana to romaji
+///
+/// Sounds that a system doesn't cover (like the extended kana used for foreign words) fall back
+/// to their Hepburn romanization.
+///
+/// ```rust
+/// # use wanakana::{to_romaji_with_options, ConversionOptions, RomanizationSystem};
+/// let romanize = |input, romanization| {
+///     let options = ConversionOptions {
+///         romanization,
+///         ..ConversionOptions::default()
+///     };
+///     to_romaji_with_options(input, &options)
+/// };
+///
+/// let input = "しちつふじぢづを ちゃっちゃ";
+/// assert_eq!(romanize(input, RomanizationSystem::Hepburn), "shichitsufujididuwo chatcha");
+/// assert_eq!(romanize(input, RomanizationSystem::KunreiShiki), "sitituhuzizizuo tyattya");
+/// assert_eq!(romanize(input, RomanizationSystem::NihonShiki), "sitituhuzididuwo tyattya");
+///
+/// // Yōon, the obsolete ゐ, and syllabic ん before a vowel or "y"
+/// let input = "しゃ じゃ ぢゃ ゐ かんい きんよう";
+/// assert_eq!(romanize(input, RomanizationSystem::Hepburn), "sha ja dya wi kan'i kin'you");
+/// assert_eq!(romanize(input, RomanizationSystem::KunreiShiki), "sya zya zya i kan'i kin'you");
+/// assert_eq!(romanize(input, RomanizationSystem::NihonShiki), "sya zya dya wi kan'i kin'you");
+/// assert_eq!(romanize(input, RomanizationSystem::Reversible), "sha ja dya wyi kan'i kin'you");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RomanizationSystem {
+    /// [Hepburn](https://en.wikipedia.org/wiki/Hepburn_romanization), e.g. "shi", "chi", "tsu",
+    /// "ji", "fu"
+    #[default]
+    Hepburn,
+
+    /// [Kunrei-shiki](https://en.wikipedia.org/wiki/Kunrei-shiki_romanization) (ISO 3602), e.g.
+    /// "si", "ti", "tu", "zi", "hu"
+    KunreiShiki,
+
+    /// [Nihon-shiki](https://en.wikipedia.org/wiki/Nihon-shiki_romanization), like Kunrei-shiki
+    /// but keeping ぢ "di", づ "du", を "wo", ゐ "wi" and ゑ "we" distinct
+    NihonShiki,
+
+    /// A lossless romanization that [`to_kana`](fn.to_kana.html) always converts back to the
+    /// original kana, in the spirit of strict Nihon-shiki. Small kana are written out ("xa",
+    /// "xtu"), ん is followed by an apostrophe wherever it could be misread, and katakana is
+    /// uppercased. The `upcase_katakana`, `long_vowels` and `long_vowel_style` options are ignored,
+    /// since they all lose information.
+    ///
+    /// Every pair of (full-width) kana survives the round trip:
+    ///
+    /// ```rust
+    /// # use wanakana::{to_kana, to_romaji_with_options, ConversionOptions, RomanizationSystem};
+    /// let options = ConversionOptions {
+    ///     romanization: RomanizationSystem::Reversible,
+    ///     ..ConversionOptions::default()
+    /// };
+    /// assert_eq!(to_romaji_with_options("ぢゃっ ゐ ンナ", &options), "dyaxtu wyi N'NA");
+    ///
+    /// let kana: Vec<char> = (0x3041..0x3100).filter_map(std::char::from_u32).collect();
+    /// for &a in &kana {
+    ///     for &b in &kana {
+    ///         let input: String = [a, b].iter().collect();
+    ///         assert_eq!(to_kana(&to_romaji_with_options(&input, &options)), input);
+    ///     }
+    /// }
+    /// ```
+    Reversible,
+}
+
+impl RomanizationSystem {
+    /// Find the romaji in this system for each chunk of hiragana at the start of `kana`, setting
+    /// `values[i]` to the romaji for `kana[..=i]` (see `Trie::prefix_values`)
+    pub(crate) fn prefix_values(self, kana: &[char], values: &mut [Option<&'static str>]) {
+        constants::TO_ROMAJI_TRIE.prefix_values(kana.iter().cloned(), values);
+
+        let system = match self {
+            RomanizationSystem::Hepburn => return,
+            RomanizationSystem::KunreiShiki => &constants::KUNREI_TO_ROMAJI_TRIE,
+            RomanizationSystem::NihonShiki => &constants::NIHON_TO_ROMAJI_TRIE,
+            RomanizationSystem::Reversible => &constants::REVERSIBLE_TO_ROMAJI_TRIE,
+        };
+
+        system.prefix_values(kana.iter().cloned(), values);
+    }
+
+    /// Test if any hiragana chunk with romaji in this system starts with `c`
+    pub(crate) fn has_key_starting_with(self, c: char) -> bool {
+        let system = match self {
+            RomanizationSystem::Hepburn => &constants::TO_ROMAJI_TRIE,
+            RomanizationSystem::KunreiShiki => &constants::KUNREI_TO_ROMAJI_TRIE,
+            RomanizationSystem::NihonShiki => &constants::NIHON_TO_ROMAJI_TRIE,
+            RomanizationSystem::Reversible => &constants::REVERSIBLE_TO_ROMAJI_TRIE,
+        };
+
+        constants::TO_ROMAJI_TRIE.has_key_starting_with(c) || system.has_key_starting_with(c)
+    }
+}
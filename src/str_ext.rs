@@ -0,0 +1,392 @@
+use halfwidth_katakana::{halfwidth_to_katakana, halfwidth_to_katakana_cow, katakana_to_halfwidth,
+                         katakana_to_halfwidth_cow};
+use hiragana_to_katakana::{hiragana_to_katakana, hiragana_to_katakana_cow,
+                           hiragana_to_katakana_with_options};
+use in_place::{make_hiragana_in_place, make_katakana_in_place};
+use is::{is_hiragana, is_hiragana_allowing, is_japanese, is_japanese_allowing, is_kana,
+         is_kana_allowing, is_kanji, is_kanji_allowing, is_kanji_with_options, is_katakana,
+         is_katakana_allowing, is_mixed, is_mixed_allowing, is_romaji, is_romaji_allowing,
+         AllowedChars, KanjiOptions};
+use katakana_to_hiragana::{katakana_to_hiragana, katakana_to_hiragana_cow,
+                           katakana_to_hiragana_with_options};
+use options::ConversionOptions;
+use passport::{to_passport_romaji, PassportOptions};
+use std::borrow::Cow;
+use strip_okurigana::{strip_okurigana, strip_okurigana_matching};
+use to_hiragana::{to_hiragana, to_hiragana_cow, to_hiragana_with_options};
+use to_kana::{to_kana, to_kana_cow, to_kana_with_options};
+use to_katakana::{to_katakana, to_katakana_cow, to_katakana_with_options};
+use to_romaji::{to_romaji, to_romaji_cow, to_romaji_with_options};
+use tokenize::{tokenize, tokenize_compact, Tokens};
+use width::{to_fullwidth, to_fullwidth_cow, to_fullwidth_with_options, to_halfwidth,
+            to_halfwidth_cow, to_halfwidth_with_options, WidthOptions};
+
+/// Every predicate and converter of the crate as a method on `str`, e.g. `"ひらがな".to_katakana()`
+///
+/// Each method is the same as the function of the same name, see the function for details.
+///
+/// ```rust
+/// # use wanakana::StrExt;
+/// assert!("ひらがな".is_hiragana());
+/// assert_eq!("ひらがな".to_katakana(), "ヒラガナ");
+/// assert_eq!("ひらがな".to_romaji(), "hiragana");
+/// assert_eq!("踏み込む".strip_okurigana(false), "踏み込");
+///
+/// let mut input = String::from("カタカナ");
+/// input.make_hiragana_in_place();
+/// assert_eq!(input, "かたかな");
+/// ```
+pub trait StrExt {
+    /// See [`is_romaji`](fn.is_romaji.html)
+    fn is_romaji(&self) -> bool;
+
+    /// See [`is_romaji_allowing`](fn.is_romaji_allowing.html)
+    fn is_romaji_allowing(&self, allowed: &AllowedChars) -> bool;
+
+    /// See [`is_japanese`](fn.is_japanese.html)
+    fn is_japanese(&self) -> bool;
+
+    /// See [`is_japanese_allowing`](fn.is_japanese_allowing.html)
+    fn is_japanese_allowing(&self, allowed: &AllowedChars) -> bool;
+
+    /// See [`is_kana`](fn.is_kana.html)
+    fn is_kana(&self) -> bool;
+
+    /// See [`is_kana_allowing`](fn.is_kana_allowing.html)
+    fn is_kana_allowing(&self, allowed: &AllowedChars) -> bool;
+
+    /// See [`is_hiragana`](fn.is_hiragana.html)
+    fn is_hiragana(&self) -> bool;
+
+    /// See [`is_hiragana_allowing`](fn.is_hiragana_allowing.html)
+    fn is_hiragana_allowing(&self, allowed: &AllowedChars) -> bool;
+
+    /// See [`is_katakana`](fn.is_katakana.html)
+    fn is_katakana(&self) -> bool;
+
+    /// See [`is_katakana_allowing`](fn.is_katakana_allowing.html)
+    fn is_katakana_allowing(&self, allowed: &AllowedChars) -> bool;
+
+    /// See [`is_kanji`](fn.is_kanji.html)
+    fn is_kanji(&self) -> bool;
+
+    /// See [`is_kanji_allowing`](fn.is_kanji_allowing.html)
+    fn is_kanji_allowing(&self, allowed: &AllowedChars) -> bool;
+
+    /// See [`is_kanji_with_options`](fn.is_kanji_with_options.html)
+    fn is_kanji_with_options(&self, options: &KanjiOptions) -> bool;
+
+    /// See [`is_mixed`](fn.is_mixed.html)
+    fn is_mixed(&self, pass_kanji: bool) -> bool;
+
+    /// See [`is_mixed_allowing`](fn.is_mixed_allowing.html)
+    fn is_mixed_allowing(&self, pass_kanji: bool, allowed: &AllowedChars) -> bool;
+
+    /// See [`to_kana`](fn.to_kana.html)
+    fn to_kana(&self) -> String;
+
+    /// See [`to_kana_with_options`](fn.to_kana_with_options.html)
+    fn to_kana_with_options(&self, options: &ConversionOptions) -> String;
+
+    /// See [`to_kana_cow`](fn.to_kana_cow.html)
+    fn to_kana_cow(&self) -> Cow<'_, str>;
+
+    /// See [`to_hiragana`](fn.to_hiragana.html)
+    fn to_hiragana(&self) -> String;
+
+    /// See [`to_hiragana_with_options`](fn.to_hiragana_with_options.html)
+    fn to_hiragana_with_options(&self, options: &ConversionOptions) -> String;
+
+    /// See [`to_hiragana_cow`](fn.to_hiragana_cow.html)
+    fn to_hiragana_cow(&self) -> Cow<'_, str>;
+
+    /// See [`to_katakana`](fn.to_katakana.html)
+    fn to_katakana(&self) -> String;
+
+    /// See [`to_katakana_with_options`](fn.to_katakana_with_options.html)
+    fn to_katakana_with_options(&self, options: &ConversionOptions) -> String;
+
+    /// See [`to_katakana_cow`](fn.to_katakana_cow.html)
+    fn to_katakana_cow(&self) -> Cow<'_, str>;
+
+    /// See [`to_romaji`](fn.to_romaji.html)
+    fn to_romaji(&self) -> String;
+
+    /// See [`to_romaji_with_options`](fn.to_romaji_with_options.html)
+    fn to_romaji_with_options(&self, options: &ConversionOptions) -> String;
+
+    /// See [`to_romaji_cow`](fn.to_romaji_cow.html)
+    fn to_romaji_cow(&self) -> Cow<'_, str>;
+
+    /// See [`hiragana_to_katakana`](fn.hiragana_to_katakana.html)
+    fn hiragana_to_katakana(&self) -> String;
+
+    /// See [`hiragana_to_katakana_with_options`](fn.hiragana_to_katakana_with_options.html)
+    fn hiragana_to_katakana_with_options(&self, options: &ConversionOptions) -> String;
+
+    /// See [`hiragana_to_katakana_cow`](fn.hiragana_to_katakana_cow.html)
+    fn hiragana_to_katakana_cow(&self) -> Cow<'_, str>;
+
+    /// See [`katakana_to_hiragana`](fn.katakana_to_hiragana.html)
+    fn katakana_to_hiragana(&self) -> String;
+
+    /// See [`katakana_to_hiragana_with_options`](fn.katakana_to_hiragana_with_options.html)
+    fn katakana_to_hiragana_with_options(&self, options: &ConversionOptions) -> String;
+
+    /// See [`katakana_to_hiragana_cow`](fn.katakana_to_hiragana_cow.html)
+    fn katakana_to_hiragana_cow(&self) -> Cow<'_, str>;
+
+    /// See [`halfwidth_to_katakana`](fn.halfwidth_to_katakana.html)
+    fn halfwidth_to_katakana(&self) -> String;
+
+    /// See [`halfwidth_to_katakana_cow`](fn.halfwidth_to_katakana_cow.html)
+    fn halfwidth_to_katakana_cow(&self) -> Cow<'_, str>;
+
+    /// See [`katakana_to_halfwidth`](fn.katakana_to_halfwidth.html)
+    fn katakana_to_halfwidth(&self) -> String;
+
+    /// See [`katakana_to_halfwidth_cow`](fn.katakana_to_halfwidth_cow.html)
+    fn katakana_to_halfwidth_cow(&self) -> Cow<'_, str>;
+
+    /// See [`to_halfwidth`](fn.to_halfwidth.html)
+    fn to_halfwidth(&self) -> String;
+
+    /// See [`to_halfwidth_with_options`](fn.to_halfwidth_with_options.html)
+    fn to_halfwidth_with_options(&self, options: &WidthOptions) -> String;
+
+    /// See [`to_halfwidth_cow`](fn.to_halfwidth_cow.html)
+    fn to_halfwidth_cow(&self) -> Cow<'_, str>;
+
+    /// See [`to_fullwidth`](fn.to_fullwidth.html)
+    fn to_fullwidth(&self) -> String;
+
+    /// See [`to_fullwidth_with_options`](fn.to_fullwidth_with_options.html)
+    fn to_fullwidth_with_options(&self, options: &WidthOptions) -> String;
+
+    /// See [`to_fullwidth_cow`](fn.to_fullwidth_cow.html)
+    fn to_fullwidth_cow(&self) -> Cow<'_, str>;
+
+    /// See [`to_passport_romaji`](fn.to_passport_romaji.html)
+    fn to_passport_romaji(&self, options: &PassportOptions) -> String;
+
+    /// See [`make_katakana_in_place`](fn.make_katakana_in_place.html)
+    fn make_katakana_in_place(&mut self);
+
+    /// See [`make_hiragana_in_place`](fn.make_hiragana_in_place.html)
+    fn make_hiragana_in_place(&mut self);
+
+    /// See [`strip_okurigana`](fn.strip_okurigana.html)
+    fn strip_okurigana(&self, leading: bool) -> &str;
+
+    /// See [`strip_okurigana_matching`](fn.strip_okurigana_matching.html), with `self` as the
+    /// reading
+    fn strip_okurigana_matching(&self, kanji: &str, leading: bool) -> &str;
+
+    /// See [`tokenize`](fn.tokenize.html)
+    fn tokenize(&self) -> Tokens<'_>;
+
+    /// See [`tokenize_compact`](fn.tokenize_compact.html)
+    fn tokenize_compact(&self) -> Tokens<'_>;
+}
+
+impl StrExt for str {
+    fn is_romaji(&self) -> bool {
+        is_romaji(self)
+    }
+
+    fn is_romaji_allowing(&self, allowed: &AllowedChars) -> bool {
+        is_romaji_allowing(self, allowed)
+    }
+
+    fn is_japanese(&self) -> bool {
+        is_japanese(self)
+    }
+
+    fn is_japanese_allowing(&self, allowed: &AllowedChars) -> bool {
+        is_japanese_allowing(self, allowed)
+    }
+
+    fn is_kana(&self) -> bool {
+        is_kana(self)
+    }
+
+    fn is_kana_allowing(&self, allowed: &AllowedChars) -> bool {
+        is_kana_allowing(self, allowed)
+    }
+
+    fn is_hiragana(&self) -> bool {
+        is_hiragana(self)
+    }
+
+    fn is_hiragana_allowing(&self, allowed: &AllowedChars) -> bool {
+        is_hiragana_allowing(self, allowed)
+    }
+
+    fn is_katakana(&self) -> bool {
+        is_katakana(self)
+    }
+
+    fn is_katakana_allowing(&self, allowed: &AllowedChars) -> bool {
+        is_katakana_allowing(self, allowed)
+    }
+
+    fn is_kanji(&self) -> bool {
+        is_kanji(self)
+    }
+
+    fn is_kanji_allowing(&self, allowed: &AllowedChars) -> bool {
+        is_kanji_allowing(self, allowed)
+    }
+
+    fn is_kanji_with_options(&self, options: &KanjiOptions) -> bool {
+        is_kanji_with_options(self, options)
+    }
+
+    fn is_mixed(&self, pass_kanji: bool) -> bool {
+        is_mixed(self, pass_kanji)
+    }
+
+    fn is_mixed_allowing(&self, pass_kanji: bool, allowed: &AllowedChars) -> bool {
+        is_mixed_allowing(self, pass_kanji, allowed)
+    }
+
+    fn to_kana(&self) -> String {
+        to_kana(self)
+    }
+
+    fn to_kana_with_options(&self, options: &ConversionOptions) -> String {
+        to_kana_with_options(self, options)
+    }
+
+    fn to_kana_cow(&self) -> Cow<'_, str> {
+        to_kana_cow(self)
+    }
+
+    fn to_hiragana(&self) -> String {
+        to_hiragana(self)
+    }
+
+    fn to_hiragana_with_options(&self, options: &ConversionOptions) -> String {
+        to_hiragana_with_options(self, options)
+    }
+
+    fn to_hiragana_cow(&self) -> Cow<'_, str> {
+        to_hiragana_cow(self)
+    }
+
+    fn to_katakana(&self) -> String {
+        to_katakana(self)
+    }
+
+    fn to_katakana_with_options(&self, options: &ConversionOptions) -> String {
+        to_katakana_with_options(self, options)
+    }
+
+    fn to_katakana_cow(&self) -> Cow<'_, str> {
+        to_katakana_cow(self)
+    }
+
+    fn to_romaji(&self) -> String {
+        to_romaji(self)
+    }
+
+    fn to_romaji_with_options(&self, options: &ConversionOptions) -> String {
+        to_romaji_with_options(self, options)
+    }
+
+    fn to_romaji_cow(&self) -> Cow<'_, str> {
+        to_romaji_cow(self)
+    }
+
+    fn hiragana_to_katakana(&self) -> String {
+        hiragana_to_katakana(self)
+    }
+
+    fn hiragana_to_katakana_with_options(&self, options: &ConversionOptions) -> String {
+        hiragana_to_katakana_with_options(self, options)
+    }
+
+    fn hiragana_to_katakana_cow(&self) -> Cow<'_, str> {
+        hiragana_to_katakana_cow(self)
+    }
+
+    fn katakana_to_hiragana(&self) -> String {
+        katakana_to_hiragana(self)
+    }
+
+    fn katakana_to_hiragana_with_options(&self, options: &ConversionOptions) -> String {
+        katakana_to_hiragana_with_options(self, options)
+    }
+
+    fn katakana_to_hiragana_cow(&self) -> Cow<'_, str> {
+        katakana_to_hiragana_cow(self)
+    }
+
+    fn halfwidth_to_katakana(&self) -> String {
+        halfwidth_to_katakana(self)
+    }
+
+    fn halfwidth_to_katakana_cow(&self) -> Cow<'_, str> {
+        halfwidth_to_katakana_cow(self)
+    }
+
+    fn katakana_to_halfwidth(&self) -> String {
+        katakana_to_halfwidth(self)
+    }
+
+    fn katakana_to_halfwidth_cow(&self) -> Cow<'_, str> {
+        katakana_to_halfwidth_cow(self)
+    }
+
+    fn to_halfwidth(&self) -> String {
+        to_halfwidth(self)
+    }
+
+    fn to_halfwidth_with_options(&self, options: &WidthOptions) -> String {
+        to_halfwidth_with_options(self, options)
+    }
+
+    fn to_halfwidth_cow(&self) -> Cow<'_, str> {
+        to_halfwidth_cow(self)
+    }
+
+    fn to_fullwidth(&self) -> String {
+        to_fullwidth(self)
+    }
+
+    fn to_fullwidth_with_options(&self, options: &WidthOptions) -> String {
+        to_fullwidth_with_options(self, options)
+    }
+
+    fn to_fullwidth_cow(&self) -> Cow<'_, str> {
+        to_fullwidth_cow(self)
+    }
+
+    fn to_passport_romaji(&self, options: &PassportOptions) -> String {
+        to_passport_romaji(self, options)
+    }
+
+    fn make_katakana_in_place(&mut self) {
+        make_katakana_in_place(self)
+    }
+
+    fn make_hiragana_in_place(&mut self) {
+        make_hiragana_in_place(self)
+    }
+
+    fn strip_okurigana(&self, leading: bool) -> &str {
+        strip_okurigana(self, leading)
+    }
+
+    fn strip_okurigana_matching(&self, kanji: &str, leading: bool) -> &str {
+        strip_okurigana_matching(self, kanji, leading)
+    }
+
+    fn tokenize(&self) -> Tokens<'_> {
+        tokenize(self)
+    }
+
+    fn tokenize_compact(&self) -> Tokens<'_> {
+        tokenize_compact(self)
+    }
+}
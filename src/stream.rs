@@ -0,0 +1,356 @@
+use composer::Composer;
+use halfwidth_katakana::HalfwidthToKatakana;
+use hiragana_to_katakana::hiragana_to_katakana_char;
+use katakana_to_hiragana::KatakanaToHiragana;
+use options::ConversionOptions;
+use output;
+use std::borrow::Cow;
+use to_romaji::RomajiConverter;
+
+/// Iterator adapters for converting a stream of chars lazily, e.g. `input.chars().to_katakana()`
+///
+/// Only as much input as a conversion needs to look ahead (or behind) is buffered, so the
+/// adapters can be used on unbounded input. They produce the same output as the `&str`
+/// converters, except that [`to_hiragana`](#method.to_hiragana) and
+/// [`to_katakana`](#method.to_katakana) can't check whether the whole input is Romaji first, so
+/// they always convert it like mixed input.
+///
+/// ```rust
+/// # use wanakana::ConvertChars;
+/// let kata: String = "toukyou, おおさか".chars().to_katakana().collect();
+/// assert_eq!(kata, "トウキョウ、 オオサカ");
+///
+/// let romaji: String = "ひらがな カタカナ".chars().to_romaji().collect();
+/// assert_eq!(romaji, "hiragana katakana");
+///
+/// let lines = vec!["きょう", "は", "いい", "てんき"];
+/// let romaji: Vec<char> = lines.iter().flat_map(|line| line.chars()).to_romaji().collect();
+/// assert_eq!(romaji.into_iter().collect::<String>(), "kyouhaiitenki");
+/// ```
+pub trait ConvertChars: Iterator<Item = char> + Sized {
+    /// Convert Romaji to Kana, see [`to_kana`](fn.to_kana.html)
+    fn to_kana(self) -> Converted<Self> {
+        self.to_kana_with_options(&ConversionOptions::default())
+    }
+
+    /// Convert Romaji to Kana using the given options, see
+    /// [`to_kana_with_options`](fn.to_kana_with_options.html)
+    fn to_kana_with_options(self, options: &ConversionOptions) -> Converted<Self> {
+        Converted::new(self, Conversion::Kana.start(options))
+    }
+
+    /// Convert input to Hiragana, see [`to_hiragana`](fn.to_hiragana.html)
+    fn to_hiragana(self) -> Converted<Self> {
+        self.to_hiragana_with_options(&ConversionOptions::default())
+    }
+
+    /// Convert input to Hiragana using the given options, see
+    /// [`to_hiragana_with_options`](fn.to_hiragana_with_options.html)
+    fn to_hiragana_with_options(self, options: &ConversionOptions) -> Converted<Self> {
+        Converted::new(self, Conversion::Hiragana.start(options))
+    }
+
+    /// Convert input to Katakana, see [`to_katakana`](fn.to_katakana.html)
+    fn to_katakana(self) -> Converted<Self> {
+        self.to_katakana_with_options(&ConversionOptions::default())
+    }
+
+    /// Convert input to Katakana using the given options, see
+    /// [`to_katakana_with_options`](fn.to_katakana_with_options.html)
+    fn to_katakana_with_options(self, options: &ConversionOptions) -> Converted<Self> {
+        Converted::new(self, Conversion::Katakana.start(options))
+    }
+
+    /// Convert Kana to Romaji, see [`to_romaji`](fn.to_romaji.html)
+    fn to_romaji(self) -> Converted<Self> {
+        self.to_romaji_with_options(&ConversionOptions::default())
+    }
+
+    /// Convert Kana to Romaji using the given options, see
+    /// [`to_romaji_with_options`](fn.to_romaji_with_options.html)
+    fn to_romaji_with_options(self, options: &ConversionOptions) -> Converted<Self> {
+        Converted::new(self, Conversion::Romaji.start(options))
+    }
+
+    /// Convert Hiragana to Katakana, see [`hiragana_to_katakana`](fn.hiragana_to_katakana.html)
+    fn hiragana_to_katakana(self) -> Converted<Self> {
+        Converted::new(self, Conversion::HiraganaToKatakana.start(&ConversionOptions::default()))
+    }
+
+    /// Convert Katakana to Hiragana, see [`katakana_to_hiragana`](fn.katakana_to_hiragana.html)
+    fn katakana_to_hiragana(self) -> Converted<Self> {
+        self.katakana_to_hiragana_with_options(&ConversionOptions::default())
+    }
+
+    /// Convert Katakana to Hiragana using the given options, see
+    /// [`katakana_to_hiragana_with_options`](fn.katakana_to_hiragana_with_options.html)
+    fn katakana_to_hiragana_with_options(self, options: &ConversionOptions) -> Converted<Self> {
+        Converted::new(self, Conversion::KatakanaToHiragana.start(options))
+    }
+}
+
+impl<I: Iterator<Item = char>> ConvertChars for I {}
+
+/// A conversion for the streaming adapters, [`ConvertReader`](struct.ConvertReader.html) and
+/// [`ConvertWriter`](struct.ConvertWriter.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Conversion {
+    /// Romaji to Kana, like [`to_kana`](fn.to_kana.html)
+    Kana,
+
+    /// Romaji and Katakana to Hiragana, like [`to_hiragana`](fn.to_hiragana.html)
+    Hiragana,
+
+    /// Romaji and Hiragana to Katakana, like [`to_katakana`](fn.to_katakana.html)
+    Katakana,
+
+    /// Kana to Romaji, like [`to_romaji`](fn.to_romaji.html)
+    Romaji,
+
+    /// Hiragana to Katakana, like [`hiragana_to_katakana`](fn.hiragana_to_katakana.html)
+    HiraganaToKatakana,
+
+    /// Katakana to Hiragana, like [`katakana_to_hiragana`](fn.katakana_to_hiragana.html)
+    KatakanaToHiragana,
+}
+
+impl Conversion {
+    /// Start a new conversion of a stream of chars
+    pub(crate) fn start(self, options: &ConversionOptions) -> Box<dyn Convert> {
+        match self {
+            Conversion::Kana => {
+                let composer = Composer::with_options(options.clone());
+                Box::new(ToKana { composer, lowercase: false })
+            }
+            Conversion::Hiragana if options.pass_romaji => Box::new(KatakanaChars::new(options)),
+            Conversion::Hiragana => {
+                let composer = Composer::with_options(options.clone());
+                let kana = ToKana { composer, lowercase: true };
+                Box::new(Chain::new(KatakanaChars::new(options), kana))
+            }
+            Conversion::Katakana if options.pass_romaji => Box::new(HiraganaChars),
+            Conversion::Katakana => {
+                let composer = Composer::katakana(options.clone());
+                let kana = ToKana { composer, lowercase: true };
+                Box::new(Chain::new(kana, HiraganaChars))
+            }
+            Conversion::Romaji => Box::new(ToRomaji {
+                halfwidth: HalfwidthToKatakana::default(),
+                converter: RomajiConverter::new(Cow::Owned(options.clone())),
+                kana: Vec::new(),
+                hira: Vec::new(),
+                buffer: String::new(),
+            }),
+            Conversion::HiraganaToKatakana => Box::new(HiraganaChars),
+            Conversion::KatakanaToHiragana => Box::new(KatakanaChars::new(options)),
+        }
+    }
+}
+
+/// An iterator over converted chars, created by the methods of
+/// [`ConvertChars`](trait.ConvertChars.html)
+pub struct Converted<I> {
+    chars: I,
+    conversion: Box<dyn Convert>,
+    output: String,
+    position: usize,
+    finished: bool,
+}
+
+impl<I> Converted<I> {
+    fn new(chars: I, conversion: Box<dyn Convert>) -> Self {
+        Converted {
+            chars,
+            conversion,
+            output: String::new(),
+            position: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Converted<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.output[self.position..].chars().next() {
+                self.position += c.len_utf8();
+                return Some(c);
+            }
+
+            if self.finished {
+                return None;
+            }
+
+            self.output.clear();
+            self.position = 0;
+
+            match self.chars.next() {
+                Some(c) => self.conversion.push(c, &mut self.output),
+                None => {
+                    self.conversion.finish(&mut self.output);
+                    self.finished = true;
+                }
+            }
+        }
+    }
+}
+
+/// A conversion that takes one char at a time, buffering what it needs to look ahead
+pub(crate) trait Convert {
+    /// Convert the next char, pushing any finished output to `out`
+    fn push(&mut self, c: char, out: &mut String);
+
+    /// Push the rest of the output to `out`, at the end of the input
+    fn finish(&mut self, out: &mut String);
+}
+
+/// Two conversions, feeding the output of the first into the second
+struct Chain<A, B> {
+    first: A,
+    second: B,
+    buffer: String,
+}
+
+impl<A, B> Chain<A, B> {
+    fn new(first: A, second: B) -> Self {
+        Chain {
+            first,
+            second,
+            buffer: String::new(),
+        }
+    }
+}
+
+impl<A: Convert, B: Convert> Convert for Chain<A, B> {
+    fn push(&mut self, c: char, out: &mut String) {
+        self.first.push(c, &mut self.buffer);
+
+        for c in self.buffer.drain(..) {
+            self.second.push(c, out);
+        }
+    }
+
+    fn finish(&mut self, out: &mut String) {
+        self.first.finish(&mut self.buffer);
+
+        for c in self.buffer.drain(..) {
+            self.second.push(c, out);
+        }
+
+        self.second.finish(out);
+    }
+}
+
+struct HiraganaChars;
+
+impl Convert for HiraganaChars {
+    fn push(&mut self, c: char, out: &mut String) {
+        out.push(hiragana_to_katakana_char(c));
+    }
+
+    fn finish(&mut self, _out: &mut String) {}
+}
+
+struct KatakanaChars {
+    halfwidth: HalfwidthToKatakana,
+    katakana: KatakanaToHiragana,
+    options: ConversionOptions,
+    buffer: String,
+}
+
+impl KatakanaChars {
+    fn new(options: &ConversionOptions) -> Self {
+        KatakanaChars {
+            halfwidth: HalfwidthToKatakana::default(),
+            katakana: KatakanaToHiragana::default(),
+            options: options.clone(),
+            buffer: String::new(),
+        }
+    }
+
+    fn convert_buffer(&mut self, out: &mut String) {
+        for c in self.buffer.drain(..) {
+            out.extend(self.katakana.convert(c, &self.options));
+        }
+    }
+}
+
+impl Convert for KatakanaChars {
+    fn push(&mut self, c: char, out: &mut String) {
+        let halfwidth = &mut self.halfwidth;
+        output::push_to(&mut self.buffer, |buffer| halfwidth.push(c, buffer));
+        self.convert_buffer(out);
+    }
+
+    fn finish(&mut self, out: &mut String) {
+        let halfwidth = &mut self.halfwidth;
+        output::push_to(&mut self.buffer, |buffer| halfwidth.finish(buffer));
+        self.convert_buffer(out);
+    }
+}
+
+struct ToKana {
+    composer: Composer,
+    lowercase: bool,
+}
+
+impl Convert for ToKana {
+    fn push(&mut self, c: char, out: &mut String) {
+        if self.lowercase {
+            for c in c.to_lowercase() {
+                self.composer.push(c);
+            }
+        } else {
+            self.composer.push(c);
+        }
+
+        self.composer.drain_committed(out);
+    }
+
+    fn finish(&mut self, out: &mut String) {
+        out.push_str(&self.composer.finish());
+    }
+}
+
+struct ToRomaji {
+    halfwidth: HalfwidthToKatakana,
+    converter: RomajiConverter<'static>,
+    kana: Vec<char>,
+    hira: Vec<char>,
+    buffer: String,
+}
+
+impl ToRomaji {
+    /// Convert as much of the buffered kana as possible, writing the romaji to `out`
+    fn convert(&mut self, is_final: bool, out: &mut String) {
+        for c in self.buffer.drain(..) {
+            self.kana.push(c);
+            self.hira.push(self.converter.next_hiragana(c));
+        }
+
+        let (converter, kana, hira) = (&mut self.converter, &self.kana, &self.hira);
+        let mut converted = 0;
+        output::push_to(out, |out| {
+            converted = converter.convert(kana, hira, is_final, out)?;
+            Ok(())
+        });
+        self.kana.drain(..converted);
+        self.hira.drain(..converted);
+    }
+}
+
+impl Convert for ToRomaji {
+    fn push(&mut self, c: char, out: &mut String) {
+        let halfwidth = &mut self.halfwidth;
+        output::push_to(&mut self.buffer, |buffer| halfwidth.push(c, buffer));
+        self.convert(false, out);
+    }
+
+    fn finish(&mut self, out: &mut String) {
+        let halfwidth = &mut self.halfwidth;
+        output::push_to(&mut self.buffer, |buffer| halfwidth.finish(buffer));
+        self.convert(true, out);
+    }
+}
@@ -0,0 +1,70 @@
+use is::{is_japanese, is_kana};
+use tokenize::{tokenize_compact, TokenKind};
+
+/// Strip [Okurigana](https://en.wikipedia.org/wiki/Okurigana) from a word containing
+/// [Kanji](https://en.wikipedia.org/wiki/Kanji). Trailing kana is stripped by default, or leading
+/// kana if `leading` is `true`.
+///
+/// Input that isn't Japanese, or that doesn't have both Kanji and Kana, is returned as-is.
+///
+/// ```rust
+/// # use wanakana::strip_okurigana;
+/// assert_eq!(strip_okurigana("踏み込む", false), "踏み込");
+/// assert_eq!(strip_okurigana("お祝い", false), "お祝");
+/// assert_eq!(strip_okurigana("お腹", false), "お腹");
+/// assert_eq!(strip_okurigana("お祝い", true), "祝い");
+/// assert_eq!(strip_okurigana("ふみこむ", false), "ふみこむ");
+/// assert_eq!(strip_okurigana("Fumikomu", false), "Fumikomu");
+/// ```
+pub fn strip_okurigana(input: &str, leading: bool) -> &str {
+    if !is_japanese(input) || is_kana(input) {
+        return input;
+    }
+
+    match okurigana(input, leading) {
+        Some(okurigana) if leading => &input[okurigana.len()..],
+        Some(okurigana) => &input[..input.len() - okurigana.len()],
+        None => input,
+    }
+}
+
+/// Strip the same [Okurigana](https://en.wikipedia.org/wiki/Okurigana) that `kanji` has from its
+/// Kana `reading`. Trailing kana is stripped by default, or leading kana if `leading` is `true`.
+///
+/// This gives the part of the reading that belongs to the Kanji, e.g. for furigana.
+///
+/// ```rust
+/// # use wanakana::strip_okurigana_matching;
+/// assert_eq!(strip_okurigana_matching("ふみこむ", "踏み込む", false), "ふみこ");
+/// assert_eq!(strip_okurigana_matching("おみまい", "お見舞い", true), "みまい");
+/// assert_eq!(strip_okurigana_matching("おなか", "お腹", false), "おなか");
+/// assert_eq!(strip_okurigana_matching("踏み込む", "踏み込む", false), "踏み込む");
+/// ```
+pub fn strip_okurigana_matching<'a>(reading: &'a str, kanji: &str, leading: bool) -> &'a str {
+    if !is_kana(reading) || !is_japanese(kanji) {
+        return reading;
+    }
+
+    let stripped = okurigana(kanji, leading).and_then(|okurigana| {
+        if leading {
+            reading.strip_prefix(okurigana)
+        } else {
+            reading.strip_suffix(okurigana)
+        }
+    });
+
+    stripped.unwrap_or(reading)
+}
+
+/// The leading or trailing run of kana in `input`, if there is one
+fn okurigana(input: &str, leading: bool) -> Option<&str> {
+    let token = if leading {
+        tokenize_compact(input).next()
+    } else {
+        tokenize_compact(input).last()
+    };
+
+    token
+        .filter(|token| token.kind == TokenKind::Kana)
+        .map(|token| token.text)
+}
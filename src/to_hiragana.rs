@@ -0,0 +1,95 @@
+use is::{is_mixed, is_romaji};
+use katakana_to_hiragana::{converts_katakana_to_hiragana,
+                           katakana_to_hiragana_into_with_options};
+use options::ConversionOptions;
+use output;
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+use std::iter;
+use to_kana::{converts_to_kana, RomajiWriter};
+
+/// Convert input to [Hiragana](https://en.wikipedia.org/wiki/Hiragana), converting both
+/// [Romaji](https://en.wikipedia.org/wiki/Romaji) and
+/// [Katakana](https://en.wikipedia.org/wiki/Katakana)
+///
+/// ```rust
+/// # use wanakana::to_hiragana;
+/// assert_eq!(to_hiragana("toukyou, オオサカ"), "とうきょう、 おおさか");
+/// assert_eq!(to_hiragana("TOUKYOU"), "とうきょう");
+/// assert_eq!(to_hiragana("ゲーム"), "げえむ");
+/// ```
+pub fn to_hiragana(input: &str) -> String {
+    to_hiragana_with_options(input, &ConversionOptions::default())
+}
+
+/// Convert input to [Hiragana](https://en.wikipedia.org/wiki/Hiragana), using the given options
+///
+/// ```rust
+/// # use wanakana::{to_hiragana_with_options, ConversionOptions};
+/// let options = ConversionOptions {
+///     pass_romaji: true,
+///     ..ConversionOptions::default()
+/// };
+/// assert_eq!(to_hiragana_with_options("only カナ", &options), "only かな");
+/// ```
+pub fn to_hiragana_with_options(input: &str, options: &ConversionOptions) -> String {
+    output::to_string(input.len(), |hira| {
+        to_hiragana_into_with_options(input, options, hira)
+    })
+}
+
+pub(crate) fn to_hiragana_into_with_options<W: fmt::Write>(
+    input: &str,
+    options: &ConversionOptions,
+    out: &mut W,
+) -> fmt::Result {
+    if options.pass_romaji {
+        katakana_to_hiragana_into_with_options(input, options, out)
+    } else if is_romaji(input) {
+        let mut romaji = RomajiWriter::new(options, false, true, out);
+        romaji.write_str(input)?;
+        romaji.finish()
+    } else if is_mixed(input, true) {
+        let mut romaji = RomajiWriter::new(options, false, true, out);
+        katakana_to_hiragana_into_with_options(input, options, &mut romaji)?;
+        romaji.finish()
+    } else {
+        katakana_to_hiragana_into_with_options(input, options, out)
+    }
+}
+
+/// Convert input to [Hiragana](https://en.wikipedia.org/wiki/Hiragana), borrowing `input` if
+/// there is nothing to convert
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use wanakana::to_hiragana_cow;
+/// assert_eq!(to_hiragana_cow("オオサカ"), "おおさか");
+/// assert!(matches!(to_hiragana_cow("ひらがな。"), Cow::Borrowed("ひらがな。")));
+/// ```
+pub fn to_hiragana_cow(input: &str) -> Cow<'_, str> {
+    to_hiragana_cow_with_options(input, &ConversionOptions::default())
+}
+
+/// Convert input to [Hiragana](https://en.wikipedia.org/wiki/Hiragana), writing the result to
+/// `out`
+pub fn to_hiragana_into<W: fmt::Write>(input: &str, out: &mut W) -> fmt::Result {
+    to_hiragana_into_with_options(input, &ConversionOptions::default(), out)
+}
+
+pub(crate) fn to_hiragana_cow_with_options<'a>(
+    input: &'a str,
+    options: &ConversionOptions,
+) -> Cow<'a, str> {
+    let converts = |c: char| {
+        converts_katakana_to_hiragana(c) ||
+            (!options.pass_romaji &&
+                 (c.to_lowercase().ne(iter::once(c)) || converts_to_kana(c, options)))
+    };
+
+    if input.chars().any(converts) {
+        Cow::Owned(to_hiragana_with_options(input, options))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
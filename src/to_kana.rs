@@ -0,0 +1,393 @@
+use char_ext::{is_consonant, is_vowel};
+use constants;
+use hiragana_to_katakana::hiragana_to_katakana_char;
+use options::{ConversionOptions, LongO};
+use output;
+use std::borrow::Cow;
+use std::cmp;
+use std::fmt::{self, Write};
+
+/// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to
+/// [Kana](https://en.wikipedia.org/wiki/Kana). Lowercase text will result in
+/// [Hiragana](https://en.wikipedia.org/wiki/Hiragana) and uppercase text will result in
+/// [Katakana](https://en.wikipedia.org/wiki/Katakana).
+///
+/// ```rust
+/// # use wanakana::to_kana;
+/// assert_eq!(to_kana("onaji BUTTSUUJI"), "おなじ ブッツウジ");
+/// assert_eq!(to_kana("ONAJI buttsuuji"), "オナジ ぶっつうじ");
+/// assert_eq!(to_kana("座禅‘zazen’スタイル"), "座禅「ざぜん」スタイル");
+/// assert_eq!(to_kana("batsuge-mu"), "ばつげーむ");
+/// assert_eq!(to_kana("!?.:/,~-‘’“”[](){}"), "！？。：・、〜ー「」『』［］（）｛｝");
+/// assert_eq!(to_kana("matcha"), "まっちゃ");
+/// assert_eq!(to_kana("Kka"), "ッか");
+/// assert_eq!(to_kana("kin'youbi konnichiha"), "きんようび こんにちは");
+/// assert_eq!(to_kana("ltsu shya"), "っ しゃ");
+/// assert_eq!(to_kana("tōkyō to Ōsaka"), "とうきょう と オーさか");
+/// assert_eq!(to_kana("TŌKYŌ"), "トーキョー");
+/// assert_eq!(to_kana("kyôto"), "きょうと");
+/// ```
+pub fn to_kana(input: &str) -> String {
+    to_kana_with_options(input, &ConversionOptions::default())
+}
+
+/// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to
+/// [Kana](https://en.wikipedia.org/wiki/Kana), using the given options
+///
+/// ```rust
+/// # use wanakana::{to_kana_with_options, ConversionOptions, LongO};
+/// let ime = ConversionOptions {
+///     ime_mode: true,
+///     ..ConversionOptions::default()
+/// };
+/// assert_eq!(to_kana_with_options("kan", &ime), "かn");
+/// assert_eq!(to_kana_with_options("kany", &ime), "かny");
+/// assert_eq!(to_kana_with_options("kanい", &ime), "かんい");
+/// assert_eq!(to_kana_with_options("kann", &ime), "かん");
+/// assert_eq!(to_kana_with_options("n ", &ime), "ん");
+///
+/// let mut custom = ConversionOptions::default();
+/// custom.custom_kana_mapping.insert("la", "ぁ");
+/// assert_eq!(to_kana_with_options("lala", &custom), "ぁぁ");
+///
+/// let long_o = ConversionOptions {
+///     long_o: LongO::Oo,
+///     ..ConversionOptions::default()
+/// };
+/// assert_eq!(to_kana_with_options("ōsaka", &long_o), "おおさか");
+///
+/// let mut custom_ou = ConversionOptions::default();
+/// custom_ou.custom_kana_mapping.insert("ou", "おー");
+/// assert_eq!(to_kana_with_options("ou", &custom_ou), "おー");
+/// assert_eq!(to_kana_with_options("ōu", &custom_ou), "おうう");
+/// ```
+pub fn to_kana_with_options(input: &str, options: &ConversionOptions) -> String {
+    romaji_to_kana(input, options, false)
+}
+
+/// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to
+/// [Kana](https://en.wikipedia.org/wiki/Kana), borrowing `input` if it has no Romaji
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use wanakana::to_kana_cow;
+/// assert_eq!(to_kana_cow("kana"), "かな");
+/// assert!(matches!(to_kana_cow("かな 123"), Cow::Borrowed("かな 123")));
+/// ```
+pub fn to_kana_cow(input: &str) -> Cow<'_, str> {
+    to_kana_cow_with_options(input, &ConversionOptions::default())
+}
+
+/// Convert [Romaji](https://en.wikipedia.org/wiki/Romaji) to
+/// [Kana](https://en.wikipedia.org/wiki/Kana), writing the result to `out`
+pub fn to_kana_into<W: fmt::Write>(input: &str, out: &mut W) -> fmt::Result {
+    to_kana_into_with_options(input, &ConversionOptions::default(), out)
+}
+
+pub(crate) fn to_kana_cow_with_options<'a>(
+    input: &'a str,
+    options: &ConversionOptions,
+) -> Cow<'a, str> {
+    if input.chars().any(|c| converts_to_kana(c, options)) {
+        Cow::Owned(to_kana_with_options(input, options))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+pub(crate) fn to_kana_into_with_options<W: fmt::Write>(
+    input: &str,
+    options: &ConversionOptions,
+    out: &mut W,
+) -> fmt::Result {
+    if input.chars().any(|c| converts_to_kana(c, options)) {
+        let mut romaji = RomajiWriter::new(options, false, false, out);
+        romaji.write_str(input)?;
+        romaji.finish()
+    } else {
+        out.write_str(input)
+    }
+}
+
+/// Test if converting `c` from romaji to kana could change it
+pub(crate) fn converts_to_kana(c: char, options: &ConversionOptions) -> bool {
+    !options.custom_kana_mapping.is_empty() ||
+        constants::FROM_ROMAJI_TRIE.has_key_starting_with(to_lower(c))
+}
+
+/// Convert romaji to kana, producing katakana for every chunk if `katakana` is `true` (otherwise
+/// only for uppercase chunks)
+pub(crate) fn romaji_to_kana(input: &str, options: &ConversionOptions, katakana: bool) -> String {
+    output::to_string(input.len() * 3, |kana| {
+        let mut romaji = RomajiWriter::new(options, katakana, false, kana);
+        romaji.write_str(input)?;
+        romaji.finish()
+    })
+}
+
+/// Convert chunks from the start of the romaji `chars` like `romaji_to_kana`, writing the kana to
+/// `kana`, and return the number of chars converted
+///
+/// Unless `is_final`, more romaji may follow, so a chunk is only converted if there's enough
+/// romaji after it for `next_kana` to find the same chunk as it would in the whole input.
+fn romaji_to_kana_into<W: fmt::Write>(
+    chars: &[char],
+    options: &ConversionOptions,
+    katakana: bool,
+    is_final: bool,
+    kana: &mut W,
+) -> Result<usize, fmt::Error> {
+    let max_chunk_size = cmp::max(3, options.custom_kana_mapping.max_key_len());
+    let lookahead = cmp::max(max_chunk_size, 4);
+    let mut cursor = 0;
+
+    while cursor < chars.len() && (is_final || chars.len() - cursor > lookahead) {
+        let (chunk_size, kana_chunk) = next_kana(&chars[cursor..], options, max_chunk_size);
+        let chunk = &chars[cursor..cursor + chunk_size];
+
+        if options.ime_mode && kana_chunk.is_some() && is_pending_n(&chars[cursor..], chunk_size) {
+            // Don't transliterate a trailing "n" (or "ny") yet, since more input may follow
+            kana.write_char(chars[cursor])?;
+        } else {
+            write_kana_chunk(kana, chunk, kana_chunk, options, katakana)?;
+        }
+
+        cursor += chunk_size;
+    }
+
+    Ok(cursor)
+}
+
+/// A writer that converts the romaji written to it to kana, only buffering as much romaji as
+/// `romaji_to_kana_into` needs to look ahead (plus a little, to convert it in batches)
+pub(crate) struct RomajiWriter<'a, 'w, W: 'w> {
+    options: &'a ConversionOptions,
+    katakana: bool,
+    lowercase: bool,
+    chars: Vec<char>,
+    kana: &'w mut W,
+}
+
+impl<'a, 'w, W: fmt::Write> RomajiWriter<'a, 'w, W> {
+    /// Convert romaji to `kana` like `romaji_to_kana`, lowercasing it first if `lowercase`
+    pub(crate) fn new(
+        options: &'a ConversionOptions,
+        katakana: bool,
+        lowercase: bool,
+        kana: &'w mut W,
+    ) -> Self {
+        RomajiWriter {
+            options,
+            katakana,
+            lowercase,
+            chars: Vec::new(),
+            kana,
+        }
+    }
+
+    /// Convert the rest of the romaji
+    pub(crate) fn finish(mut self) -> fmt::Result {
+        self.convert(true)
+    }
+
+    fn convert(&mut self, is_final: bool) -> fmt::Result {
+        let (options, katakana) = (self.options, self.katakana);
+        let converted = romaji_to_kana_into(&self.chars, options, katakana, is_final, self.kana)?;
+        self.chars.drain(..converted);
+        Ok(())
+    }
+}
+
+impl<'a, 'w, W: fmt::Write> fmt::Write for RomajiWriter<'a, 'w, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let batch_size = 64 + cmp::max(4, self.options.custom_kana_mapping.max_key_len());
+
+        for c in s.chars() {
+            if self.lowercase {
+                self.chars.extend(c.to_lowercase());
+            } else {
+                self.chars.push(c);
+            }
+
+            if self.chars.len() >= batch_size {
+                self.convert(false)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Write the kana for a chunk of romaji returned by `next_kana`, producing katakana if `katakana`
+/// is `true` or the chunk starts with an uppercase letter
+pub(crate) fn write_kana_chunk<W: fmt::Write>(
+    kana: &mut W,
+    chunk: &[char],
+    kana_chunk: Option<&str>,
+    options: &ConversionOptions,
+    katakana: bool,
+) -> fmt::Result {
+    let katakana = katakana || strip_long_vowel(chunk[0]).is_ascii_uppercase();
+
+    match kana_chunk {
+        Some(kana_chunk) if katakana => {
+            for c in kana_chunk.chars() {
+                kana.write_char(hiragana_to_katakana_char(c))?;
+            }
+        }
+        Some(kana_chunk) => kana.write_str(kana_chunk)?,
+        // Pass unknown chars through
+        None => return kana.write_char(chunk[0]),
+    }
+
+    // Lengthen the vowel if the chunk ends with a macron or circumflex ("ō" -> "おう" or "オー")
+    match chunk.last().and_then(|&c| long_vowel_kana(c, options)) {
+        Some(_) if katakana => kana.write_char('ー'),
+        Some(long_vowel) => kana.write_str(long_vowel),
+        None => Ok(()),
+    }
+}
+
+/// Find the longest romaji chunk at the start of `chars`, returning the number of chars consumed
+/// and the matching hiragana (or `None` if the first char has no kana equivalent)
+pub(crate) fn next_kana<'a>(
+    chars: &[char],
+    options: &'a ConversionOptions,
+    max_chunk_size: usize,
+) -> (usize, Option<&'a str>) {
+    // A vowel with a macron or circumflex ends the chunk, so that its lengthening follows the
+    // chunk's kana ("ōu" is "おう" + "う", even with a custom mapping for "ou")
+    let longest_chunk = &chars[..cmp::min(cmp::max(max_chunk_size, 4), chars.len())];
+    let chars = match longest_chunk.iter().position(|&c| strip_long_vowel(c) != c) {
+        Some(index) => &chars[..index + 1],
+        None => chars,
+    };
+
+    // The first few chars (enough for the special cases below), lowercased
+    let mut lower = ['\0'; 4];
+    for (lower, &c) in lower.iter_mut().zip(chars) {
+        *lower = to_lower(c);
+    }
+
+    // The kana for each chunk size that's in the table, found in a single walk of the trie
+    let mut table = [None; 4];
+    constants::FROM_ROMAJI_TRIE.prefix_values(chars.iter().map(|&c| to_lower(c)), &mut table);
+    let table_kana = |chunk_size: usize| table.get(chunk_size - 1).cloned().flatten();
+
+    let mut chunk_size = cmp::min(max_chunk_size, chars.len());
+
+    while chunk_size > 0 {
+        if chunk_size == 3 && chars.len() >= 4 && is_four_char_edgecase(&lower[..3]) {
+            // Handle super-rare edge cases with 4 char chunks (like ltsu, chya, shya)
+            if let Some(kana) = lookup(&chars[..4], table_kana(4), options) {
+                return (4, Some(kana));
+            }
+        } else if lower[0] == 'n' {
+            // Keep the space after a trailing n (unless typing into an IME, where it confirms)
+            if chunk_size == 2 && lower[1] == ' ' && !options.ime_mode {
+                return (2, Some("ん "));
+            }
+
+            // Handle edge case of n followed by a consonant and vowel ("nna" -> "んな")
+            if chunk_size == 3 && is_consonant(lower[1], false) && is_vowel(lower[2]) {
+                return (1, Some("ん"));
+            }
+        } else if chunk_size > 1 && is_consonant(lower[0], true) &&
+                   (lower[0] == lower[1] || (chunk_size == 3 && lower[..3] == ['t', 'c', 'h']))
+        {
+            // Handle double consonants ("kk" -> "っk", "tch" -> "っch")
+            return (1, Some("っ"));
+        }
+
+        if let Some(kana) = lookup(&chars[..chunk_size], table_kana(chunk_size), options) {
+            return (chunk_size, Some(kana));
+        }
+
+        chunk_size -= 1;
+    }
+
+    (1, None)
+}
+
+/// Find the kana for a chunk of romaji, where `table_kana` is its kana in `FROM_ROMAJI_TRIE`
+fn lookup<'a>(
+    chunk: &[char],
+    table_kana: Option<&'static str>,
+    options: &'a ConversionOptions,
+) -> Option<&'a str> {
+    if !options.custom_kana_mapping.is_empty() {
+        let chunk: String = chunk.iter().map(|&c| to_lower(c)).collect();
+
+        if let Some(kana) = options.custom_kana_mapping.get(&chunk) {
+            return kana;
+        }
+    }
+
+    if options.use_obsolete_kana && chunk.len() == 2 && to_lower(chunk[0]) == 'w' {
+        match to_lower(chunk[1]) {
+            'i' => return Some("ゐ"),
+            'e' => return Some("ゑ"),
+            _ => {}
+        }
+    }
+
+    table_kana
+}
+
+fn is_four_char_edgecase(chunk: &[char]) -> bool {
+    constants::FOUR_CHAR_EDGECASES
+        .iter()
+        .any(|edgecase| edgecase.chars().eq(chunk.iter().cloned()))
+}
+
+/// Test if `chars` is a trailing "n" (or "ny") that an IME would keep as romaji until the next
+/// keystroke. Anywhere else, the following input has already decided it, just like it has for a
+/// [`Composer`](struct.Composer.html).
+fn is_pending_n(chars: &[char], chunk_size: usize) -> bool {
+    if chunk_size != 1 || !chars[0].eq_ignore_ascii_case(&'n') {
+        return false;
+    }
+
+    match chars.get(1) {
+        None => true,
+        Some(&c) if c.eq_ignore_ascii_case(&'y') => chars.len() == 2,
+        Some(_) => false,
+    }
+}
+
+/// Lowercase `c`, and convert it to the plain vowel if it's written with a macron or circumflex
+fn to_lower(c: char) -> char {
+    strip_long_vowel(c).to_ascii_lowercase()
+}
+
+/// Convert a vowel written with a macron or circumflex to the plain vowel, keeping its case
+fn strip_long_vowel(c: char) -> char {
+    if c.is_ascii() {
+        return c;
+    }
+
+    let mut buffer = [0; 4];
+    let c_str: &str = c.encode_utf8(&mut buffer);
+
+    constants::FROM_MACRON_VOWELS
+        .get::<str>(c_str)
+        .or_else(|| constants::FROM_CIRCUMFLEX_VOWELS.get::<str>(c_str))
+        .and_then(|vowel| vowel.chars().next())
+        .unwrap_or(c)
+}
+
+/// The hiragana that lengthens `c`, if it's a vowel written with a macron or circumflex
+fn long_vowel_kana(c: char, options: &ConversionOptions) -> Option<&'static str> {
+    let vowel = strip_long_vowel(c);
+
+    if vowel == c {
+        None
+    } else if vowel.eq_ignore_ascii_case(&'o') && options.long_o == LongO::Oo {
+        Some("お")
+    } else {
+        let mut buffer = [0; 4];
+        constants::LONG_VOWELS
+            .get::<str>(vowel.to_ascii_lowercase().encode_utf8(&mut buffer))
+            .cloned()
+    }
+}
@@ -0,0 +1,91 @@
+use char_ext::CharExt;
+use hiragana_to_katakana::{hiragana_to_katakana_into_with_options, KatakanaWriter};
+use is::{is_mixed, is_romaji};
+use options::ConversionOptions;
+use output;
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+use std::iter;
+use to_kana::{converts_to_kana, RomajiWriter};
+
+/// Convert input to [Katakana](https://en.wikipedia.org/wiki/Katakana), converting both
+/// [Romaji](https://en.wikipedia.org/wiki/Romaji) and
+/// [Hiragana](https://en.wikipedia.org/wiki/Hiragana)
+///
+/// ```rust
+/// # use wanakana::to_katakana;
+/// assert_eq!(to_katakana("toukyou, おおさか"), "トウキョウ、 オオサカ");
+/// assert_eq!(to_katakana("geemu"), "ゲエム");
+/// assert_eq!(to_katakana("げーむ"), "ゲーム");
+/// assert_eq!(to_katakana("tōkyō"), "トーキョー");
+/// ```
+pub fn to_katakana(input: &str) -> String {
+    to_katakana_with_options(input, &ConversionOptions::default())
+}
+
+/// Convert input to [Katakana](https://en.wikipedia.org/wiki/Katakana), using the given options
+///
+/// ```rust
+/// # use wanakana::{to_katakana_with_options, ConversionOptions};
+/// let options = ConversionOptions {
+///     pass_romaji: true,
+///     ..ConversionOptions::default()
+/// };
+/// assert_eq!(to_katakana_with_options("only かな", &options), "only カナ");
+/// ```
+pub fn to_katakana_with_options(input: &str, options: &ConversionOptions) -> String {
+    output::to_string(input.len(), |kata| {
+        to_katakana_into_with_options(input, options, kata)
+    })
+}
+
+pub(crate) fn to_katakana_into_with_options<W: fmt::Write>(
+    input: &str,
+    options: &ConversionOptions,
+    out: &mut W,
+) -> fmt::Result {
+    if !options.pass_romaji && (is_romaji(input) || is_mixed(input, true)) {
+        let mut kata = KatakanaWriter(out);
+        let mut romaji = RomajiWriter::new(options, true, true, &mut kata);
+        romaji.write_str(input)?;
+        romaji.finish()
+    } else {
+        hiragana_to_katakana_into_with_options(input, options, out)
+    }
+}
+
+/// Convert input to [Katakana](https://en.wikipedia.org/wiki/Katakana), borrowing `input` if
+/// there is nothing to convert
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use wanakana::to_katakana_cow;
+/// assert_eq!(to_katakana_cow("おおさか"), "オオサカ");
+/// assert!(matches!(to_katakana_cow("カタカナ。"), Cow::Borrowed("カタカナ。")));
+/// ```
+pub fn to_katakana_cow(input: &str) -> Cow<'_, str> {
+    to_katakana_cow_with_options(input, &ConversionOptions::default())
+}
+
+/// Convert input to [Katakana](https://en.wikipedia.org/wiki/Katakana), writing the result to
+/// `out`
+pub fn to_katakana_into<W: fmt::Write>(input: &str, out: &mut W) -> fmt::Result {
+    to_katakana_into_with_options(input, &ConversionOptions::default(), out)
+}
+
+pub(crate) fn to_katakana_cow_with_options<'a>(
+    input: &'a str,
+    options: &ConversionOptions,
+) -> Cow<'a, str> {
+    let converts = |c: char| {
+        c.is_hiragana() ||
+            (!options.pass_romaji &&
+                 (c.to_lowercase().ne(iter::once(c)) || converts_to_kana(c, options)))
+    };
+
+    if input.chars().any(converts) {
+        Cow::Owned(to_katakana_with_options(input, options))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
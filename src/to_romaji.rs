@@ -0,0 +1,426 @@
+use char_ext::{is_consonant, is_vowel, CharExt};
+use constants;
+use halfwidth_katakana::{is_halfwidth_kana, HalfwidthToKatakana};
+use katakana_to_hiragana::KatakanaToHiragana;
+use options::{ConversionOptions, LongVowelStyle, LongVowels};
+use output;
+use romanization::RomanizationSystem;
+use std::borrow::Cow;
+use std::cmp;
+use std::fmt::{self, Write};
+
+/// Convert [Kana](https://en.wikipedia.org/wiki/Kana) to
+/// [Romaji](https://en.wikipedia.org/wiki/Romaji)
+///
+/// ```rust
+/// # use wanakana::to_romaji;
+/// assert_eq!(to_romaji("ひらがな　カタカナ"), "hiragana katakana");
+/// assert_eq!(to_romaji("げーむ　ゲーム"), "ge-mu geemu");
+/// assert_eq!(to_romaji("きゃっかん"), "kyakkan");
+/// assert_eq!(to_romaji("まっちゃ"), "matcha");
+/// assert_eq!(to_romaji("きんえん"), "kin'en");
+/// assert_eq!(to_romaji("東京へいく"), "東京heiku");
+/// assert_eq!(to_romaji("ｶﾞｯｺｳ"), "gakkou");
+/// assert_eq!(to_romaji("ヷ ヸ ヹ ヺ"), "ヷ ヸ ヹ ヺ"); // No romaji for the kana without hiragana
+/// ```
+pub fn to_romaji(input: &str) -> String {
+    to_romaji_with_options(input, &ConversionOptions::default())
+}
+
+/// Convert [Kana](https://en.wikipedia.org/wiki/Kana) to
+/// [Romaji](https://en.wikipedia.org/wiki/Romaji), using the given options
+///
+/// ```rust
+/// # use wanakana::{to_romaji_with_options, ConversionOptions};
+/// let options = ConversionOptions {
+///     upcase_katakana: true,
+///     ..ConversionOptions::default()
+/// };
+/// assert_eq!(to_romaji_with_options("ひらがな　カタカナ", &options), "hiragana KATAKANA");
+/// assert_eq!(to_romaji_with_options("ロッカー", &options), "ROKKAA");
+///
+/// let mut custom = ConversionOptions::default();
+/// custom.custom_romaji_mapping.insert("じ", "zi");
+/// assert_eq!(to_romaji_with_options("じてんしゃ", &custom), "zitensha");
+/// ```
+pub fn to_romaji_with_options(input: &str, options: &ConversionOptions) -> String {
+    output::to_string(input.len(), |romaji| {
+        write_romaji(input, options, romaji)
+    })
+}
+
+/// Convert kana to romaji like `to_romaji_with_options`, writing the romaji to `romaji`
+fn write_romaji<W: fmt::Write>(
+    input: &str,
+    options: &ConversionOptions,
+    romaji: &mut W,
+) -> fmt::Result {
+    let mut kana = KanaWriter {
+        converter: RomajiConverter::new(Cow::Borrowed(options)),
+        kana: Vec::new(),
+        hira: Vec::new(),
+        romaji,
+    };
+
+    // Half-width kana has to be made full-width first, which can join two chars into one
+    if input.chars().any(is_halfwidth_kana) {
+        let mut halfwidth = HalfwidthToKatakana::default();
+        for c in input.chars() {
+            halfwidth.push(c, &mut kana)?;
+        }
+        halfwidth.finish(&mut kana)?;
+    } else {
+        kana.write_str(input)?;
+    }
+
+    kana.convert(true)
+}
+
+/// A writer that converts the (full-width) kana written to it to romaji, only buffering as much
+/// kana as the `RomajiConverter` needs to look ahead (plus a little, to convert it in batches)
+struct KanaWriter<'a, 'w, W: 'w> {
+    converter: RomajiConverter<'a>,
+    // Katakana (and its prolonged sound marks) is handled by converting to hiragana first, which
+    // keeps the number of chars the same, so `kana` and `hira` can be indexed together
+    kana: Vec<char>,
+    hira: Vec<char>,
+    romaji: &'w mut W,
+}
+
+impl<'a, 'w, W: fmt::Write> KanaWriter<'a, 'w, W> {
+    fn convert(&mut self, is_final: bool) -> fmt::Result {
+        let converted = self.converter.convert(&self.kana, &self.hira, is_final, self.romaji)?;
+        self.kana.drain(..converted);
+        self.hira.drain(..converted);
+        Ok(())
+    }
+}
+
+impl<'a, 'w, W: fmt::Write> fmt::Write for KanaWriter<'a, 'w, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let batch_size = 64 + self.converter.max_chunk_size;
+
+        for c in s.chars() {
+            self.kana.push(c);
+            self.hira.push(self.converter.next_hiragana(c));
+
+            if self.kana.len() >= batch_size {
+                self.convert(false)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The state of a kana to romaji conversion, so that kana can be converted a chunk at a time
+pub(crate) struct RomajiConverter<'a> {
+    options: Cow<'a, ConversionOptions>,
+    reversible: bool,
+    katakana: KatakanaToHiragana,
+    max_chunk_size: usize,
+    double_next_consonant: bool,
+    held_vowel: Option<char>,
+    follows_n: bool,
+}
+
+impl<'a> RomajiConverter<'a> {
+    pub(crate) fn new(options: Cow<'a, ConversionOptions>) -> Self {
+        // Reversible romanization can't drop the information these options throw away
+        let reversible = options.romanization == RomanizationSystem::Reversible;
+        let options = if reversible {
+            Cow::Owned(ConversionOptions {
+                upcase_katakana: true,
+                long_vowels: LongVowels::Keep,
+                long_vowel_style: LongVowelStyle::Wapuro,
+                ..options.into_owned()
+            })
+        } else {
+            options
+        };
+
+        RomajiConverter {
+            max_chunk_size: cmp::max(2, options.custom_romaji_mapping.max_key_len()),
+            options,
+            reversible,
+            katakana: KatakanaToHiragana::default(),
+            double_next_consonant: false,
+            held_vowel: None,
+            follows_n: false,
+        }
+    }
+
+    /// Convert the next kana to the hiragana to look up its romaji with, keeping the kana if it's
+    /// a long vowel mark that can't be expanded
+    pub(crate) fn next_hiragana(&mut self, c: char) -> char {
+        self.katakana.convert(c, &self.options).unwrap_or(c)
+    }
+
+    /// Convert chunks from the start of `kana` (and `hira`, the same kana converted by
+    /// `next_hiragana`), writing to `romaji`, and return the number of chars converted
+    ///
+    /// Unless `is_final`, more kana may follow, so a chunk is only converted if there's enough
+    /// kana after it to know how it's romanized. A vowel ending a chunk is held back until the
+    /// next chunk, which may lengthen it.
+    pub(crate) fn convert<W: fmt::Write>(
+        &mut self,
+        kana: &[char],
+        hira: &[char],
+        is_final: bool,
+        romaji: &mut W,
+    ) -> Result<usize, fmt::Error> {
+        let options = &*self.options;
+        let reversible = self.reversible;
+        let mut cursor = 0;
+
+        while cursor < hira.len() && (is_final || hira.len() - cursor > self.max_chunk_size) {
+            // Reversible romaji doesn't join kana across a change of script, since the case of
+            // the romaji chunk decides whether it converts back to hiragana or katakana
+            let end = if reversible {
+                script_run_end(kana, cursor)
+            } else {
+                hira.len()
+            };
+
+            // Small tsu doubles the consonant of the following kana
+            if hira[cursor] == 'っ' && cursor + 1 < end &&
+                !(reversible && self.double_next_consonant)
+            {
+                let (_, next) = next_romaji(&hira[cursor + 1..end], options, self.max_chunk_size);
+
+                if !reversible || next.is_some_and(is_doubled_by_sokuon) {
+                    self.double_next_consonant = true;
+                    cursor += 1;
+                    continue;
+                }
+            }
+
+            let (chunk_size, roma) = next_romaji(&hira[cursor..end], options, self.max_chunk_size);
+            let upcase = options.upcase_katakana &&
+                kana[cursor..cursor + chunk_size].iter().all(|c| c.is_katakana());
+
+            let long_vowel = self.held_vowel.filter(|&vowel| {
+                chunk_size == 1 && options.long_vowel_style != LongVowelStyle::Wapuro &&
+                    is_lengthening(vowel, hira[cursor], kana[cursor])
+            });
+
+            if let Some(vowel) = long_vowel {
+                write_long_vowel(romaji, vowel, options.long_vowel_style)?;
+                self.held_vowel = None;
+            } else {
+                if let Some(vowel) = self.held_vowel.take() {
+                    romaji.write_char(vowel)?;
+                }
+
+                match roma {
+                    Some(roma) => {
+                        let case = |c: char| if upcase { c.to_ascii_uppercase() } else { c };
+
+                        // Separate ん from a following vowel, "y" or "n" ("ンア" -> "N'A")
+                        if reversible && self.follows_n && roma.starts_with(is_ambiguous_after_n) {
+                            romaji.write_char('\'')?;
+                        }
+
+                        if self.double_next_consonant {
+                            if let Some(consonant) = sokuon_consonant(roma) {
+                                romaji.write_char(case(consonant))?;
+                            }
+                        }
+
+                        let mut chars = roma.chars().map(case);
+                        let last = chars.next_back();
+                        for c in chars {
+                            romaji.write_char(c)?;
+                        }
+
+                        match last {
+                            Some(vowel) if is_vowel(vowel) => self.held_vowel = Some(vowel),
+                            Some(c) => romaji.write_char(c)?,
+                            None => {}
+                        }
+                    }
+                    // Pass unknown chars through
+                    None if reversible => romaji.write_char(kana[cursor])?,
+                    None => romaji.write_char(hira[cursor])?,
+                }
+
+                self.follows_n = roma == Some("n");
+            }
+
+            self.double_next_consonant = false;
+            cursor += chunk_size;
+        }
+
+        if is_final {
+            if let Some(vowel) = self.held_vowel.take() {
+                romaji.write_char(vowel)?;
+            }
+        }
+
+        Ok(cursor)
+    }
+}
+
+/// Convert [Kana](https://en.wikipedia.org/wiki/Kana) to
+/// [Romaji](https://en.wikipedia.org/wiki/Romaji), borrowing `input` if it has no Kana
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use wanakana::to_romaji_cow;
+/// assert_eq!(to_romaji_cow("かな"), "kana");
+/// assert!(matches!(to_romaji_cow("no kana here"), Cow::Borrowed("no kana here")));
+/// ```
+pub fn to_romaji_cow(input: &str) -> Cow<'_, str> {
+    to_romaji_cow_with_options(input, &ConversionOptions::default())
+}
+
+/// Convert [Kana](https://en.wikipedia.org/wiki/Kana) to
+/// [Romaji](https://en.wikipedia.org/wiki/Romaji), writing the result to `out`
+///
+/// ```rust
+/// # use wanakana::to_romaji_into;
+/// let mut line = String::new();
+///
+/// for input in &["ERROR: ", "ファイルがない"] {
+///     to_romaji_into(input, &mut line).unwrap();
+/// }
+/// assert_eq!(line, "ERROR: fairuganai");
+/// ```
+pub fn to_romaji_into<W: fmt::Write>(input: &str, out: &mut W) -> fmt::Result {
+    to_romaji_into_with_options(input, &ConversionOptions::default(), out)
+}
+
+pub(crate) fn to_romaji_cow_with_options<'a>(
+    input: &'a str,
+    options: &ConversionOptions,
+) -> Cow<'a, str> {
+    if converts_to_romaji(input, options) {
+        Cow::Owned(to_romaji_with_options(input, options))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+pub(crate) fn to_romaji_into_with_options<W: fmt::Write>(
+    input: &str,
+    options: &ConversionOptions,
+    out: &mut W,
+) -> fmt::Result {
+    if converts_to_romaji(input, options) {
+        write_romaji(input, options, out)
+    } else {
+        out.write_str(input)
+    }
+}
+
+/// Test if converting `input` to romaji could change it
+fn converts_to_romaji(input: &str, options: &ConversionOptions) -> bool {
+    let converts = |c: char| {
+        c.is_kana() || is_halfwidth_kana(c) || options.romanization.has_key_starting_with(c)
+    };
+
+    !options.custom_romaji_mapping.is_empty() || input.chars().any(converts)
+}
+
+/// Find the longest kana chunk at the start of `chars`, returning the number of chars consumed
+/// and the matching romaji (or `None` if the first char has no romaji equivalent)
+fn next_romaji<'a>(
+    chars: &[char],
+    options: &'a ConversionOptions,
+    max_chunk_size: usize,
+) -> (usize, Option<&'a str>) {
+    let mut table = [None; 2];
+    options.romanization.prefix_values(chars, &mut table);
+
+    let mut chunk_size = cmp::min(max_chunk_size, chars.len());
+
+    while chunk_size > 0 {
+        let custom = if options.custom_romaji_mapping.is_empty() {
+            None
+        } else {
+            let chunk: String = chars[..chunk_size].iter().collect();
+            options.custom_romaji_mapping.get(&chunk)
+        };
+
+        let roma = match custom {
+            Some(roma) => roma,
+            None => table.get(chunk_size - 1).cloned().flatten(),
+        };
+
+        if roma.is_some() {
+            return (chunk_size, roma);
+        }
+
+        chunk_size -= 1;
+    }
+
+    (1, None)
+}
+
+/// Find the end of the run of katakana (or of non-katakana) chars starting at `start`
+fn script_run_end(kana: &[char], start: usize) -> usize {
+    let is_katakana = kana[start].is_katakana();
+
+    kana[start..]
+        .iter()
+        .position(|c| c.is_katakana() != is_katakana)
+        .map_or(kana.len(), |len| start + len)
+}
+
+/// Test if a small tsu before `roma` can be written by doubling its first consonant, in a way
+/// that [`to_kana`](fn.to_kana.html) converts back (unlike "nn", which is read as ん)
+fn is_doubled_by_sokuon(roma: &str) -> bool {
+    roma.chars().next().is_some_and(|c| c != 'n' && is_consonant(c, true))
+}
+
+/// Test if `c` would be read as part of a preceding "n", rather than as the start of a new kana
+fn is_ambiguous_after_n(c: char) -> bool {
+    is_vowel(c) || c == 'y' || c == 'n'
+}
+
+/// Find the doubled consonant for a small tsu preceding `roma` ("っか" -> "kka", "っち" -> "tchi")
+fn sokuon_consonant(roma: &str) -> Option<char> {
+    if roma.starts_with("ch") {
+        Some('t')
+    } else {
+        roma.chars().next().filter(|&c| is_consonant(c, true))
+    }
+}
+
+/// Test if the kana following a romaji `vowel` lengthens it, where `hira` is the kana converted to
+/// hiragana and `kana` is the original kana
+fn is_lengthening(vowel: char, hira: char, kana: char) -> bool {
+    if (kana as u32) == constants::PROLONGED_SOUND_MARK {
+        return true;
+    }
+
+    matches!(
+        (vowel.to_ascii_lowercase(), hira),
+        ('a', 'あ') | ('u', 'う') | ('e', 'え') | ('o', 'う') | ('o', 'お')
+    )
+}
+
+/// Write the long form of `vowel` in the given style
+fn write_long_vowel<W: fmt::Write>(
+    romaji: &mut W,
+    vowel: char,
+    style: LongVowelStyle,
+) -> fmt::Result {
+    let mut buf = [0; 4];
+    let vowel_str: &str = vowel.encode_utf8(&mut buf);
+
+    let long_vowel = match style {
+        LongVowelStyle::Macron => constants::MACRON_VOWELS.get(vowel_str),
+        LongVowelStyle::Circumflex => constants::CIRCUMFLEX_VOWELS.get(vowel_str),
+        LongVowelStyle::Doubled => {
+            romaji.write_char(vowel)?;
+            None
+        }
+        LongVowelStyle::Wapuro | LongVowelStyle::Omitted => None,
+    };
+
+    match long_vowel {
+        Some(long_vowel) => romaji.write_str(long_vowel),
+        None => romaji.write_char(vowel),
+    }
+}
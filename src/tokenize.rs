@@ -0,0 +1,178 @@
+use char_ext::{self, CharExt};
+use constants;
+use std::ops::Range;
+
+/// The kind of script (or other class of characters) a [`Token`](struct.Token.html) consists of
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// [Hiragana](https://en.wikipedia.org/wiki/Hiragana), e.g. "ひらがな"
+    Hiragana,
+    /// [Katakana](https://en.wikipedia.org/wiki/Katakana) (full or half-width), e.g. "カタカナ"
+    Katakana,
+    /// Hiragana and/or Katakana, only produced by
+    /// [`tokenize_compact`](fn.tokenize_compact.html)
+    Kana,
+    /// [Kanji](https://en.wikipedia.org/wiki/Kanji), e.g. "漢字"
+    Kanji,
+    /// [Romaji](https://en.wikipedia.org/wiki/Romaji) and other latin letters, e.g. "Tōkyō"
+    Romaji,
+    /// Japanese (zenkaku) punctuation and symbols, e.g. "。「」"
+    JapanesePunctuation,
+    /// English (hankaku) punctuation and smart quotes, e.g. ".“”"
+    EnglishPunctuation,
+    /// Zenkaku numbers, e.g. "１２３"
+    JapaneseNumeral,
+    /// Latin numbers, e.g. "123"
+    LatinNumeral,
+    /// Whitespace, including the ideographic space "　"
+    Space,
+    /// Anything else, e.g. emoji
+    Other,
+}
+
+/// A run of characters of the same [`TokenKind`](enum.TokenKind.html)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    /// The kind of characters in this token
+    pub kind: TokenKind,
+    /// The text of this token
+    pub text: &'a str,
+    /// The byte range of `text` within the input
+    pub span: Range<usize>,
+}
+
+/// Iterator over the [`Token`](struct.Token.html)s of a string, see
+/// [`tokenize`](fn.tokenize.html)
+#[derive(Clone, Debug)]
+pub struct Tokens<'a> {
+    input: &'a str,
+    position: usize,
+    compact: bool,
+}
+
+/// Split `input` into runs of characters of the same [`TokenKind`](enum.TokenKind.html)
+///
+/// ```rust
+/// # use wanakana::{tokenize, TokenKind};
+/// let tokens: Vec<_> = tokenize("ふふフフ").map(|token| (token.kind, token.text)).collect();
+/// assert_eq!(tokens, [(TokenKind::Hiragana, "ふふ"), (TokenKind::Katakana, "フフ")]);
+///
+/// let tokens: Vec<_> = tokenize("感じ 2つ、ゲーム!")
+///     .map(|token| (token.kind, token.text, token.span))
+///     .collect();
+/// assert_eq!(
+///     tokens,
+///     [
+///         (TokenKind::Kanji, "感", 0..3),
+///         (TokenKind::Hiragana, "じ", 3..6),
+///         (TokenKind::Space, " ", 6..7),
+///         (TokenKind::LatinNumeral, "2", 7..8),
+///         (TokenKind::Hiragana, "つ", 8..11),
+///         (TokenKind::JapanesePunctuation, "、", 11..14),
+///         (TokenKind::Katakana, "ゲーム", 14..23),
+///         (TokenKind::EnglishPunctuation, "!", 23..24),
+///     ]
+/// );
+///
+/// // The marks used like Kanji are part of Kanji tokens, as with `is_kanji`
+/// let tokens: Vec<_> = tokenize("人々と霞ヶ関").map(|token| (token.kind, token.text)).collect();
+/// assert_eq!(
+///     tokens,
+///     [
+///         (TokenKind::Kanji, "人々"),
+///         (TokenKind::Hiragana, "と"),
+///         (TokenKind::Kanji, "霞ヶ関"),
+///     ]
+/// );
+/// ```
+pub fn tokenize(input: &str) -> Tokens<'_> {
+    Tokens {
+        input,
+        position: 0,
+        compact: false,
+    }
+}
+
+/// Split `input` into runs of characters like [`tokenize`](fn.tokenize.html), but merging
+/// adjacent Hiragana and Katakana into a single [`TokenKind::Kana`](enum.TokenKind.html) token
+///
+/// ```rust
+/// # use wanakana::{tokenize_compact, TokenKind};
+/// let tokens: Vec<_> = tokenize_compact("ふふフフ漢字")
+///     .map(|token| (token.kind, token.text))
+///     .collect();
+/// assert_eq!(tokens, [(TokenKind::Kana, "ふふフフ"), (TokenKind::Kanji, "漢字")]);
+/// ```
+pub fn tokenize_compact(input: &str) -> Tokens<'_> {
+    Tokens {
+        input,
+        position: 0,
+        compact: true,
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let start = self.position;
+        let mut chars = self.input[start..].char_indices();
+        let kind = chars.next().map(|(_, c)| self.kind_of(c, None))?;
+
+        let len = chars
+            .find(|&(_, c)| self.kind_of(c, Some(kind)) != kind)
+            .map_or(self.input.len() - start, |(offset, _)| offset);
+
+        self.position = start + len;
+
+        Some(Token {
+            kind,
+            text: &self.input[start..self.position],
+            span: start..self.position,
+        })
+    }
+}
+
+impl<'a> Tokens<'a> {
+    fn kind_of(&self, c: char, previous: Option<TokenKind>) -> TokenKind {
+        match token_kind(c, previous) {
+            TokenKind::Hiragana | TokenKind::Katakana if self.compact => TokenKind::Kana,
+            kind => kind,
+        }
+    }
+}
+
+fn token_kind(c: char, previous: Option<TokenKind>) -> TokenKind {
+    if c.is_whitespace() {
+        TokenKind::Space
+    } else if char_ext::is_in_range(c, &constants::ZENKAKU_NUMBERS) {
+        TokenKind::JapaneseNumeral
+    } else if char_ext::is_in_range(c, &constants::LATIN_NUMBERS) {
+        TokenKind::LatinNumeral
+    } else if char_ext::is_in_ranges(c, constants::EN_PUNCTUATION_RANGES) {
+        TokenKind::EnglishPunctuation
+    } else if char_ext::is_in_ranges(c, constants::KANJI_MARK_RANGES) {
+        TokenKind::Kanji
+    } else if (c as u32) == constants::PROLONGED_SOUND_MARK {
+        // The prolonged sound mark continues whichever kana came before it
+        match previous {
+            Some(kind @ TokenKind::Hiragana) | Some(kind @ TokenKind::Kana) => kind,
+            _ => TokenKind::Katakana,
+        }
+    } else if char_ext::is_in_ranges(c, constants::JA_PUNCTUATION_RANGES) {
+        TokenKind::JapanesePunctuation
+    } else if char_ext::is_in_ranges(c, &[constants::COMMON_CJK, constants::RARE_CJK]) ||
+        char_ext::is_in_ranges(c, constants::SUPPLEMENTARY_CJK_RANGES) ||
+        char_ext::is_in_ranges(c, constants::COMPATIBILITY_CJK_RANGES)
+    {
+        TokenKind::Kanji
+    } else if char_ext::is_in_range(c, &constants::HIRAGANA_CHARS) {
+        TokenKind::Hiragana
+    } else if c.is_kana() {
+        TokenKind::Katakana
+    } else if c.is_alphabetic() && c.is_romaji() {
+        TokenKind::Romaji
+    } else {
+        TokenKind::Other
+    }
+}
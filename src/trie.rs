@@ -0,0 +1,76 @@
+/// A [trie](https://en.wikipedia.org/wiki/Trie) generated by the build script from one of the
+/// mapping tables, so every key that prefixes some input can be found in a single pass without
+/// allocating
+pub(crate) struct Trie(pub(crate) &'static [TrieNode]);
+
+/// A trie node, with the value of the key ending at this node (if any) and the indexes of its
+/// children sorted by char. The root node is at index 0.
+pub(crate) struct TrieNode {
+    pub(crate) value: Option<&'static str>,
+    pub(crate) children: &'static [(char, u16)],
+}
+
+impl Trie {
+    /// Walk the trie along `chars`, setting `values[i]` to the value of the key `chars[..=i]` for
+    /// each such key. Entries without a matching key are left untouched, so another trie can be
+    /// walked over the same `values` to take precedence over this one.
+    pub(crate) fn prefix_values<I>(&self, chars: I, values: &mut [Option<&'static str>])
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let mut node = &self.0[0];
+
+        for (value, c) in values.iter_mut().zip(chars) {
+            node = match node.children.binary_search_by_key(&c, |&(key, _)| key) {
+                Ok(index) => &self.0[node.children[index].1 as usize],
+                Err(_) => return,
+            };
+
+            if node.value.is_some() {
+                *value = node.value;
+            }
+        }
+    }
+
+    /// Test if any key starts with `c`
+    pub(crate) fn has_key_starting_with(&self, c: char) -> bool {
+        self.0[0]
+            .children
+            .binary_search_by_key(&c, |&(key, _)| key)
+            .is_ok()
+    }
+
+    /// Test if any key is longer than `prefix` and starts with it
+    pub(crate) fn has_longer_key<I>(&self, prefix: I) -> bool
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let mut node = &self.0[0];
+
+        for c in prefix {
+            node = match node.children.binary_search_by_key(&c, |&(key, _)| key) {
+                Ok(index) => &self.0[node.children[index].1 as usize],
+                Err(_) => return false,
+            };
+        }
+
+        !node.children.is_empty()
+    }
+
+    /// Look up the value of the key `chars`
+    pub(crate) fn get<I>(&self, chars: I) -> Option<&'static str>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let mut node = &self.0[0];
+
+        for c in chars {
+            node = match node.children.binary_search_by_key(&c, |&(key, _)| key) {
+                Ok(index) => &self.0[node.children[index].1 as usize],
+                Err(_) => return None,
+            };
+        }
+
+        node.value
+    }
+}
@@ -0,0 +1,205 @@
+use char_ext;
+use constants;
+use output;
+use std::borrow::Cow;
+use std::char;
+use std::fmt;
+
+/// Which classes of characters [`to_halfwidth`](fn.to_halfwidth.html) and
+/// [`to_fullwidth`](fn.to_fullwidth.html) convert. All classes are converted by default.
+///
+/// ```rust
+/// # use wanakana::{to_halfwidth_with_options, WidthOptions};
+/// let options = WidthOptions {
+///     punctuation: false,
+///     space: false,
+///     ..WidthOptions::default()
+/// };
+/// assert_eq!(to_halfwidth_with_options("ＡＢＣ１２３！　", &options), "ABC123！　");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WidthOptions {
+    /// Convert the letters `A-Z` and `a-z`
+    pub letters: bool,
+
+    /// Convert the digits `0-9`
+    pub digits: bool,
+
+    /// Convert ASCII punctuation and symbols, like `!` and `@`
+    pub punctuation: bool,
+
+    /// Convert between the ASCII space and the ideographic space "　"
+    pub space: bool,
+}
+
+impl Default for WidthOptions {
+    fn default() -> Self {
+        WidthOptions {
+            letters: true,
+            digits: true,
+            punctuation: true,
+            space: true,
+        }
+    }
+}
+
+impl WidthOptions {
+    /// Test if the ASCII character `c` (or its full-width form) should be converted
+    fn converts(&self, c: char) -> bool {
+        if c.is_ascii_alphabetic() {
+            self.letters
+        } else if c.is_ascii_digit() {
+            self.digits
+        } else if c.is_ascii_punctuation() {
+            self.punctuation
+        } else {
+            c == ' ' && self.space
+        }
+    }
+}
+
+/// Convert full-width (zenkaku) letters, digits, punctuation and the ideographic space to their
+/// half-width (hankaku) ASCII equivalents
+///
+/// ```rust
+/// # use wanakana::to_halfwidth;
+/// assert_eq!(to_halfwidth("ＡＢＣ１２３"), "ABC123");
+/// assert_eq!(to_halfwidth("ｗａｎａｋａｎａ！　＠＃＄"), "wanakana! @#$");
+/// assert_eq!(to_halfwidth("カタカナ。"), "カタカナ。");
+/// ```
+pub fn to_halfwidth(input: &str) -> String {
+    to_halfwidth_with_options(input, &WidthOptions::default())
+}
+
+/// Convert full-width (zenkaku) characters to half-width (hankaku), only converting the classes
+/// of characters enabled in `options`
+pub fn to_halfwidth_with_options(input: &str, options: &WidthOptions) -> String {
+    output::to_string(input.len(), |out| to_halfwidth_into_with_options(input, options, out))
+}
+
+/// Convert ASCII letters, digits, punctuation and spaces to their full-width (zenkaku) forms
+///
+/// ```rust
+/// # use wanakana::to_fullwidth;
+/// assert_eq!(to_fullwidth("ABC123"), "ＡＢＣ１２３");
+/// assert_eq!(to_fullwidth("wanakana! @#$"), "ｗａｎａｋａｎａ！　＠＃＄");
+/// assert_eq!(to_fullwidth("カタカナ。"), "カタカナ。");
+/// ```
+pub fn to_fullwidth(input: &str) -> String {
+    to_fullwidth_with_options(input, &WidthOptions::default())
+}
+
+/// Convert ASCII characters to full-width (zenkaku), only converting the classes of characters
+/// enabled in `options`
+///
+/// ```rust
+/// # use wanakana::{to_fullwidth_with_options, WidthOptions};
+/// let options = WidthOptions {
+///     letters: false,
+///     ..WidthOptions::default()
+/// };
+/// assert_eq!(to_fullwidth_with_options("ABC 123", &options), "ABC　１２３");
+/// ```
+pub fn to_fullwidth_with_options(input: &str, options: &WidthOptions) -> String {
+    output::to_string(input.len() * 3, |out| to_fullwidth_into_with_options(input, options, out))
+}
+
+/// Convert full-width characters to half-width, borrowing `input` if there are none
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use wanakana::to_halfwidth_cow;
+/// assert_eq!(to_halfwidth_cow("ＡＢＣ"), "ABC");
+/// assert!(matches!(to_halfwidth_cow("ABC"), Cow::Borrowed("ABC")));
+/// ```
+pub fn to_halfwidth_cow(input: &str) -> Cow<'_, str> {
+    let options = WidthOptions::default();
+
+    if input.chars().any(|c| halfwidth_char(c, &options) != c) {
+        Cow::Owned(to_halfwidth_with_options(input, &options))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Convert full-width characters to half-width, writing the result to `out`
+pub fn to_halfwidth_into<W: fmt::Write>(input: &str, out: &mut W) -> fmt::Result {
+    to_halfwidth_into_with_options(input, &WidthOptions::default(), out)
+}
+
+fn to_halfwidth_into_with_options<W: fmt::Write>(
+    input: &str,
+    options: &WidthOptions,
+    out: &mut W,
+) -> fmt::Result {
+    for c in input.chars() {
+        out.write_char(halfwidth_char(c, options))?;
+    }
+
+    Ok(())
+}
+
+/// Convert ASCII characters to full-width, borrowing `input` if there are none
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use wanakana::to_fullwidth_cow;
+/// assert_eq!(to_fullwidth_cow("ABC"), "ＡＢＣ");
+/// assert!(matches!(to_fullwidth_cow("ＡＢＣ"), Cow::Borrowed("ＡＢＣ")));
+/// ```
+pub fn to_fullwidth_cow(input: &str) -> Cow<'_, str> {
+    let options = WidthOptions::default();
+
+    if input.chars().any(|c| fullwidth_char(c, &options) != c) {
+        Cow::Owned(to_fullwidth_with_options(input, &options))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+/// Convert ASCII characters to full-width, writing the result to `out`
+pub fn to_fullwidth_into<W: fmt::Write>(input: &str, out: &mut W) -> fmt::Result {
+    to_fullwidth_into_with_options(input, &WidthOptions::default(), out)
+}
+
+fn to_fullwidth_into_with_options<W: fmt::Write>(
+    input: &str,
+    options: &WidthOptions,
+    out: &mut W,
+) -> fmt::Result {
+    for c in input.chars() {
+        out.write_char(fullwidth_char(c, options))?;
+    }
+
+    Ok(())
+}
+
+fn halfwidth_char(c: char, options: &WidthOptions) -> char {
+    let is_zenkaku_ascii =
+        char_ext::is_between(c, constants::ZENKAKU_ASCII_START, constants::ZENKAKU_ASCII_END);
+
+    let halfwidth = if (c as u32) == constants::IDEOGRAPHIC_SPACE {
+        ' '
+    } else if is_zenkaku_ascii {
+        // The full-width forms of ASCII are all offset by the same amount
+        char::from_u32(c as u32 - constants::ZENKAKU_ASCII_OFFSET).unwrap_or(c)
+    } else {
+        return c;
+    };
+
+    if options.converts(halfwidth) {
+        halfwidth
+    } else {
+        c
+    }
+}
+
+fn fullwidth_char(c: char, options: &WidthOptions) -> char {
+    if !c.is_ascii() || !options.converts(c) {
+        c
+    } else if c == ' ' {
+        char::from_u32(constants::IDEOGRAPHIC_SPACE).unwrap_or(c)
+    } else {
+        char::from_u32(c as u32 + constants::ZENKAKU_ASCII_OFFSET).unwrap_or(c)
+    }
+}
@@ -0,0 +1,12 @@
+#!/bin/sh
+# Replace the vendored UCD files with the unmodified files of a Unicode release from unicode.org,
+# e.g. `ucd/update.sh 16.0.0`. Bump the `UNICODE_VERSION` doc test in src/classify.rs to match.
+set -eu
+
+version=${1:?usage: ucd/update.sh <unicode version, e.g. 16.0.0>}
+dir=$(dirname "$0")
+
+for file in Blocks.txt Scripts.txt EastAsianWidth.txt; do
+    curl --fail --silent --show-error --location \
+        --output "$dir/$file" "https://www.unicode.org/Public/$version/ucd/$file"
+done